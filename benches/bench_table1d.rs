@@ -19,8 +19,8 @@ fn lookup<Search, LowerBound, UpperBound>(
     lookup_values: &[f64],
 ) where
     Search: lookup_tables::Search<f64>,
-    LowerBound: Bound<f64>,
-    UpperBound: Bound<f64>,
+    LowerBound: Bound<f64, f64>,
+    UpperBound: Bound<f64, f64>,
 {
     for value in lookup_values {
         table.lookup(black_box(*value));
@@ -36,6 +36,7 @@ struct BenchPack {
 }
 
 impl BenchPack {
+    #[allow(clippy::default_constructed_unit_structs)]
     fn new(
         rng: &mut StdRng,
         len: usize,
@@ -100,6 +101,8 @@ fn make_static_ref<T>(x: T) -> &'static T {
     Box::leak(Box::new(x))
 }
 
+// each benchmarked call stays wrapped in `black_box` so it cannot be optimized out
+#[allow(clippy::unit_arg)]
 fn lookup_random() -> impl IntoBenchmarks {
     let mut rng = StdRng::seed_from_u64(10);
 
@@ -132,17 +135,17 @@ fn lookup_random() -> impl IntoBenchmarks {
 
         benchmarks.extend(vec![
             benchmark_fn(format!("random linear clamped {len}"), move |b| {
-                b.iter(move || black_box(lookup(&linear_clamped, &lookup_values)))
+                b.iter(move || black_box(lookup(linear_clamped, lookup_values)))
             }),
             benchmark_fn(format!("random linear interp {len}"), move |b| {
-                b.iter(move || black_box(lookup(&linear_interp, &lookup_values)))
+                b.iter(move || black_box(lookup(linear_interp, lookup_values)))
             }),
             benchmark_fn(format!("random binary interp {len}"), move |b| {
-                b.iter(move || black_box(lookup(&binary_interp, &lookup_values)))
+                b.iter(move || black_box(lookup(binary_interp, lookup_values)))
             }),
             benchmark_fn(
                 format!("random cached linear cell interp {len}"),
-                move |b| b.iter(move || black_box(lookup(&cached_linear_interp, &lookup_values))),
+                move |b| b.iter(move || black_box(lookup(cached_linear_interp, lookup_values))),
             ),
         ])
     }
@@ -150,6 +153,8 @@ fn lookup_random() -> impl IntoBenchmarks {
     benchmarks
 }
 
+// each benchmarked call stays wrapped in `black_box` so it cannot be optimized out
+#[allow(clippy::unit_arg)]
 fn lookup_sorted() -> impl IntoBenchmarks {
     let mut rng = StdRng::seed_from_u64(10);
 
@@ -182,17 +187,17 @@ fn lookup_sorted() -> impl IntoBenchmarks {
 
         benchmarks.extend(vec![
             benchmark_fn(format!("sorted linear clamped {len}"), move |b| {
-                b.iter(move || black_box(lookup(&linear_clamped, &lookup_values)))
+                b.iter(move || black_box(lookup(linear_clamped, lookup_values)))
             }),
             benchmark_fn(format!("sorted linear interp {len}"), move |b| {
-                b.iter(move || black_box(lookup(&linear_interp, &lookup_values)))
+                b.iter(move || black_box(lookup(linear_interp, lookup_values)))
             }),
             benchmark_fn(format!("sorted binary interp {len}"), move |b| {
-                b.iter(move || black_box(lookup(&binary_interp, &lookup_values)))
+                b.iter(move || black_box(lookup(binary_interp, lookup_values)))
             }),
             benchmark_fn(
                 format!("sorted cached_linear_cell interp {len}"),
-                move |b| b.iter(move || black_box(lookup(&cached_linear_interp, &lookup_values))),
+                move |b| b.iter(move || black_box(lookup(cached_linear_interp, lookup_values))),
             ),
         ])
     }
@@ -1,3 +1,4 @@
+use crate::interpolation;
 use std::marker::PhantomData;
 
 // todo: axis should specify the behavior at the bounds
@@ -14,11 +15,15 @@ use std::marker::PhantomData;
 /// * `Search` - search method implementing the [Search](crate::Search) trait
 /// * `LowerBound` - bounding behavior at the lower bound of the axis implementing the [Bound](crate::Bound) trait
 /// * `UpperBound` - bounding behavior at the higher bound of the axis implementing the [Bound](crate::Bound) trait
-pub struct Axis<Indep, Search, LowerBound, UpperBound> {
+/// * `Interpolation` - interpolation between grid points of the axis implementing the
+///   [Interpolation](crate::Interpolation) trait. Defaults to linear interpolation
+pub struct Axis<Indep, Search, LowerBound, UpperBound, Interpolation = interpolation::LinearInterp>
+{
     _indep: PhantomData<Indep>,
     _search: PhantomData<Search>,
     _lower_bound: PhantomData<LowerBound>,
     _upper_bound: PhantomData<UpperBound>,
+    _interpolation: PhantomData<Interpolation>,
 }
 
 /// Helper trait to extract information on an axis at compile time
@@ -27,13 +32,15 @@ pub trait AxisImpl {
     type Search;
     type LowerBound;
     type UpperBound;
+    type Interpolation;
 }
 
-impl<Indep, Search, LowerBound, UpperBound> AxisImpl
-    for Axis<Indep, Search, LowerBound, UpperBound>
+impl<Indep, Search, LowerBound, UpperBound, Interpolation> AxisImpl
+    for Axis<Indep, Search, LowerBound, UpperBound, Interpolation>
 {
     type Indep = Indep;
     type Search = Search;
     type LowerBound = LowerBound;
     type UpperBound = UpperBound;
+    type Interpolation = Interpolation;
 }
@@ -1,3 +1,10 @@
+use crate::interpolation;
+use crate::{Error, LookupError};
+use std::ops::{Add, Mul, Neg, Rem, Sub};
+
+#[cfg(feature = "num-traits")]
+use num_traits::Float;
+
 /// Interpolate out of bounds using the first / last two grid points.
 ///
 /// # Example
@@ -22,6 +29,7 @@
 /// assert!(table.lookup(-10.) == -20.); // lower bound is interpolated on. using `f(5.0) = 10.` and `f(10.) = 20.0`
 /// assert!(table.lookup(20.) == 40.);
 /// ```
+#[derive(Default, Debug, Clone, Copy)]
 pub struct Interp;
 
 /// Clamp interpolation results to the value of the independent variable at the bounds.
@@ -49,92 +57,1684 @@ pub struct Interp;
 /// // upper bound is clamped, saturates to f(x) = 2 * 10 = 20
 /// assert!(table.lookup(20.) == 20.);
 /// ```
+#[derive(Default, Debug, Clone, Copy)]
 pub struct Clamp;
 
-/// Defines how to treat a lookup of an independent variable its upper and lower bounds.
-pub trait Bound<Indep> {
-    /// Behavior at the upper bound.
-    fn upper_bound(indep: Indep, upper_bound: Indep) -> Indep;
+/// Refuse to look up values beyond the bounds of the independent variable.
+///
+/// Checked lookups (`try_lookup`) return [LookupError::OutOfBounds](crate::LookupError::OutOfBounds)
+/// naming the axis, the value and the range of the axis. Unchecked lookups (`lookup`) cannot
+/// return an error and clamp the same way as [Clamp].
+///
+/// # Example
+///
+/// ```
+/// use lookup_tables::{Axis, Binary, Interp, LookupError, LookupTable1D, Reject};
+///
+/// // extrapolation is allowed below the table, but not above it
+/// type RejectUpperAxis = Axis<f64, Binary, Interp, Reject>;
+///
+/// let x = vec![0., 5.0, 10.];
+/// let y = x.iter().map(|value| 2.0 * value).collect();
+///
+/// let table = LookupTable1D::<RejectUpperAxis, f64>::new(x, Binary::new(), y).unwrap();
+///
+/// assert!(table.try_lookup(5.) == Ok(10.));
+/// assert!(table.try_lookup(-5.) == Ok(-10.));
+///
+/// let error = table.try_lookup(20.).unwrap_err();
+/// assert!(error == LookupError::OutOfBounds { axis: 0, value: 20., lower: 0., upper: 10. });
+///
+/// // unchecked lookups are clamped
+/// assert!(table.lookup(20.) == 20.);
+/// ```
+#[derive(Default, Debug, Clone, Copy)]
+pub struct Reject;
 
-    /// Behavior at the lower bound.
-    fn lower_bound(indep: Indep, lower_bound: Indep) -> Indep;
+/// Return a constant dependent variable beyond the bounds of the independent variable, instead of
+/// interpolating. For tables with multiple axes, the value is returned if any axis bounded with
+/// `Fill` is out of bounds.
+///
+/// # Example
+///
+/// ```
+/// use lookup_tables::{Axis, Binary, Clamp, Fill, LookupTable1D};
+///
+/// // no thrust is produced above the table, lookups below the table are clamped
+/// type ThrustAxis = Axis<f64, Binary, Clamp, Fill<f64>>;
+///
+/// let x = vec![0., 5.0, 10.];
+/// let y = vec![100., 50., 10.];
+///
+/// let table = LookupTable1D::<ThrustAxis, f64>::with_bounds(x, Binary::new(), Clamp, Fill::new(0.), y)
+///     .unwrap();
+///
+/// assert!(table.lookup(5.) == 50.);
+/// assert!(table.lookup(10.) == 10.);
+/// assert!(table.lookup(-5.) == 100.);
+/// assert!(table.lookup(20.) == 0.);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Fill<Dep> {
+    value: Dep,
+}
+
+impl<Dep> Fill<Dep> {
+    pub fn new(value: Dep) -> Self {
+        Self { value }
+    }
+}
+
+/// Interpolate out of bounds using the first / last two grid points, up to a margin beyond the
+/// bound. Lookups further out of bounds are clamped at the margin.
+///
+/// The margin is either an absolute distance in the independent variable, or a fraction of the
+/// span of the independent variable.
+///
+/// # Example
+///
+/// ```
+/// use lookup_tables::{Axis, Binary, InterpMargin, LookupTable1D};
+///
+/// type MarginAxis = Axis<f64, Binary, InterpMargin<f64>, InterpMargin<f64>>;
+///
+/// let x = vec![0., 5.0, 10.];
+/// // y = 2.0 * x
+/// let y = x.iter().map(|value| 2.0 * value).collect();
+///
+/// // interpolate up to 1.0 below the table, and 10% of the span (1.0) above the table
+/// let table = LookupTable1D::<MarginAxis, f64>::with_bounds(
+///     x,
+///     Binary::new(),
+///     InterpMargin::absolute(1.0),
+///     InterpMargin::fraction_of_span(0.1),
+///     y,
+/// )
+/// .unwrap();
+///
+/// assert!(table.lookup(-0.5) == -1.);
+/// assert!(table.lookup(-10.) == -2.);
+/// assert!(table.lookup(10.5) == 21.);
+/// assert!(table.lookup(20.) == 22.);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct InterpMargin<Indep> {
+    margin: Indep,
+    span_fraction: Option<Indep>,
 }
 
-impl<Indep> Bound<Indep> for Clamp
+impl<Indep> InterpMargin<Indep>
 where
-    Indep: std::cmp::PartialOrd,
+    Indep: Copy,
 {
-    fn upper_bound(indep: Indep, upper_bound: Indep) -> Indep {
-        // dont use std::cmp::max here because it requires Ord, which floats dont have
-        if indep > upper_bound {
-            upper_bound
+    /// Interpolate up to `margin` beyond the bound
+    pub fn absolute(margin: Indep) -> Self {
+        Self {
+            margin,
+            span_fraction: None,
+        }
+    }
+
+    /// Interpolate up to `fraction` of the span of the independent variable beyond the bound
+    pub fn fraction_of_span(fraction: Indep) -> Self {
+        Self {
+            margin: fraction,
+            span_fraction: Some(fraction),
+        }
+    }
+}
+
+/// Extrapolate out of bounds with a least squares line or quadratic through the first / last
+/// `points` grid points. Averaging over several grid points is less sensitive than [Interp] to
+/// noise in the final interval of the table.
+///
+/// A line through two points extrapolates with the end slope of the table, like [Interp]. A
+/// quadratic through three points extrapolates with both the end slope and curvature. Construction
+/// fails with [Error::FitPoints](crate::Error::FitPoints) if there are too few points for the fit,
+/// or more points than the independent variable.
+///
+/// # Example
+///
+/// ```
+/// use lookup_tables::{Axis, Binary, LeastSquares, LookupTable1D};
+///
+/// type FitAxis = Axis<f64, Binary, LeastSquares<f64>, LeastSquares<f64>>;
+///
+/// let x = vec![0., 1., 2., 3., 4.];
+/// // y = x^2
+/// let y = vec![0., 1., 4., 9., 16.];
+///
+/// let table = LookupTable1D::<FitAxis, f64>::with_bounds(
+///     x,
+///     Binary::new(),
+///     LeastSquares::line(2),
+///     LeastSquares::quadratic(3),
+///     y,
+/// )
+/// .unwrap();
+///
+/// // line through (0, 0) and (1, 1)
+/// assert!((table.lookup(-1.) - -1.).abs() < 1e-12);
+/// // quadratic through the last three points
+/// assert!((table.lookup(5.) - 25.).abs() < 1e-12);
+/// ```
+#[cfg(feature = "num-traits")]
+#[derive(Debug, Clone, Copy)]
+pub struct LeastSquares<Indep> {
+    degree: usize,
+    points: usize,
+    lower: Option<NormalEquations<Indep>>,
+    upper: Option<NormalEquations<Indep>>,
+}
+
+/// Inverse of the normal equations of a least squares fit through the grid points starting at
+/// `start`, with the independent variable offset by `center`
+#[cfg(feature = "num-traits")]
+#[derive(Debug, Clone, Copy)]
+struct NormalEquations<Indep> {
+    start: usize,
+    center: Indep,
+    inverse: [[Indep; 3]; 3],
+}
+
+#[cfg(feature = "num-traits")]
+impl<Indep> LeastSquares<Indep> {
+    /// Fit a line through `points` grid points
+    pub fn line(points: usize) -> Self {
+        Self {
+            degree: 1,
+            points,
+            lower: None,
+            upper: None,
+        }
+    }
+
+    /// Fit a quadratic through `points` grid points
+    pub fn quadratic(points: usize) -> Self {
+        Self {
+            degree: 2,
+            points,
+            lower: None,
+            upper: None,
+        }
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<Indep> LeastSquares<Indep>
+where
+    Indep: Float,
+{
+    /// Invert the normal equations of the fit through `points` grid points from `start`
+    #[allow(clippy::needless_range_loop)]
+    fn normal_equations(
+        &self,
+        indep_values: &[Indep],
+        start: usize,
+        center: Indep,
+    ) -> NormalEquations<Indep> {
+        let size = self.degree + 1;
+
+        // sums of the powers of the offset independent variable
+        let mut sums = [Indep::zero(); 5];
+        for indep in &indep_values[start..start + self.points] {
+            let offset = *indep - center;
+            let mut power = Indep::one();
+
+            for sum in sums.iter_mut().take(2 * self.degree + 1) {
+                *sum = *sum + power;
+                power = power * offset;
+            }
+        }
+
+        let mut matrix = [[Indep::zero(); 3]; 3];
+        let mut inverse = [[Indep::zero(); 3]; 3];
+        for i in 0..size {
+            matrix[i][..size].copy_from_slice(&sums[i..i + size]);
+            inverse[i][i] = Indep::one();
+        }
+
+        // gauss-jordan elimination. the matrix is symmetric positive definite, so pivoting is not
+        // required
+        for i in 0..size {
+            let pivot = matrix[i][i];
+            for j in 0..size {
+                matrix[i][j] = matrix[i][j] / pivot;
+                inverse[i][j] = inverse[i][j] / pivot;
+            }
+
+            for k in 0..size {
+                if k == i {
+                    continue;
+                }
+
+                let factor = matrix[k][i];
+                for j in 0..size {
+                    matrix[k][j] = matrix[k][j] - factor * matrix[i][j];
+                    inverse[k][j] = inverse[k][j] - factor * inverse[i][j];
+                }
+            }
+        }
+
+        NormalEquations {
+            start,
+            center,
+            inverse,
+        }
+    }
+
+    /// Weights of the fit evaluated at `indep`
+    fn fit(&self, normal_equations: &NormalEquations<Indep>, indep: Indep) -> Fit<Indep> {
+        let offset = indep - normal_equations.center;
+        let basis = [Indep::one(), offset, offset * offset];
+
+        let coefficients = normal_equations.inverse.map(|row| {
+            row.iter()
+                .zip(basis.iter())
+                .fold(Indep::zero(), |sum, (inverse, basis)| {
+                    sum + *inverse * *basis
+                })
+        });
+
+        Fit {
+            start: normal_equations.start,
+            end: normal_equations.start + self.points,
+            center: normal_equations.center,
+            coefficients,
+        }
+    }
+}
+
+/// Reflect the independent variable about a point of symmetry, so that only one half of a
+/// symmetric table needs to be stored.
+///
+/// As a lower bound, values below the point of symmetry are reflected above it. As an upper bound,
+/// values above the point of symmetry are reflected below it. The point of symmetry defaults to the
+/// bound of the table itself. Reflected values beyond the other bound of the table are bounded by
+/// the other bound as usual.
+///
+/// Antisymmetric tables (`f(-x) = -f(x)`) also negate the dependent variable of reflected values,
+/// including values returned by a [Fill] bound on the other side of the table.
+///
+/// # Example
+///
+/// ```
+/// use lookup_tables::{Axis, Binary, Clamp, LookupTable1D, Reflect};
+///
+/// // store sideslip angles of 0 to 20 degrees only
+/// type SideslipAxis = Axis<f64, Binary, Reflect<f64>, Clamp>;
+///
+/// let sideslip = vec![0., 10., 20.];
+/// let side_force = vec![0., -1., -1.5];
+///
+/// let table = LookupTable1D::<SideslipAxis, f64>::with_bounds(
+///     sideslip,
+///     Binary::new(),
+///     Reflect::new().antisymmetric(),
+///     Clamp,
+///     side_force,
+/// )
+/// .unwrap();
+///
+/// assert!(table.lookup(5.) == -0.5);
+/// assert!(table.lookup(-5.) == 0.5);
+/// assert!(table.lookup(-30.) == 1.5);
+/// ```
+#[derive(Default, Debug, Clone, Copy)]
+pub struct Reflect<Indep> {
+    center: Option<Indep>,
+    antisymmetric: bool,
+}
+
+impl<Indep> Reflect<Indep> {
+    /// Reflect about the bound of the table
+    pub fn new() -> Self {
+        Self {
+            center: None,
+            antisymmetric: false,
+        }
+    }
+
+    /// Reflect about `center`
+    pub fn about(center: Indep) -> Self {
+        Self {
+            center: Some(center),
+            antisymmetric: false,
+        }
+    }
+
+    /// Negate the dependent variable of reflected values
+    pub fn antisymmetric(mut self) -> Self {
+        self.antisymmetric = true;
+        self
+    }
+}
+
+impl<Indep> Reflect<Indep>
+where
+    Indep: Copy + Add<Output = Indep> + Sub<Output = Indep>,
+{
+    fn reflect<Dep>(&self, indep: Indep, center: Indep) -> Bounded<Indep, Dep>
+    where
+        Dep: Neg<Output = Dep>,
+    {
+        let reflected = center + (center - indep);
+
+        if self.antisymmetric {
+            Bounded::Negate(reflected, Dep::neg)
         } else {
-            indep
+            Bounded::Indep(reflected)
         }
     }
+}
 
-    fn lower_bound(indep: Indep, lower_bound: Indep) -> Indep {
-        // dont use std::cmp::min here because it requires Ord, which floats dont have
-        if indep < lower_bound {
-            lower_bound
+/// Wrap the independent variable into the range of the table, for axes such as angles and phases
+/// which repeat every `period`.
+///
+/// When the table is constructed, an entry one period above the first entry is appended to the
+/// independent variable, with the dependent variable of the first entry, so that lookups between
+/// the last and first entries interpolate across the seam. Tables do not need to include the
+/// duplicated endpoint themselves, but may. Construction fails with
+/// [Error::PeriodTooShort](crate::Error::PeriodTooShort) if the independent variable spans more
+/// than one period, and with [Error::PeriodMismatch](crate::Error::PeriodMismatch) unless both
+/// bounds of the axis are periodic with the same period.
+///
+/// # Example
+///
+/// ```
+/// use lookup_tables::{Axis, Binary, LookupTable1D, Periodic};
+///
+/// type HeadingAxis = Axis<f64, Binary, Periodic<f64>, Periodic<f64>>;
+///
+/// let heading = vec![0., 90., 180., 270.];
+/// let y = vec![0., 10., 20., 30.];
+///
+/// let table = LookupTable1D::<HeadingAxis, f64>::with_bounds(
+///     heading,
+///     Binary::new(),
+///     Periodic::new(360.),
+///     Periodic::new(360.),
+///     y,
+/// )
+/// .unwrap();
+///
+/// assert!(table.lookup(45.) == 5.);
+/// // interpolates between 270 and 360 degrees, where the table wraps back to 0 degrees
+/// assert!(table.lookup(315.) == 15.);
+/// assert!(table.lookup(-45.) == 15.);
+/// assert!(table.lookup(360. + 45.) == 5.);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Periodic<Indep> {
+    period: Indep,
+}
+
+impl<Indep> Periodic<Indep> {
+    pub fn new(period: Indep) -> Self {
+        Self { period }
+    }
+}
+
+impl<Indep> Periodic<Indep>
+where
+    Indep: Copy + PartialOrd + Add<Output = Indep> + Sub<Output = Indep> + Rem<Output = Indep>,
+{
+    /// Wrap `indep` into the period starting at `start`
+    fn wrap(&self, indep: Indep, start: Indep) -> Indep {
+        let offset = (indep - start) % self.period;
+
+        if indep < start {
+            start + (offset + self.period)
         } else {
-            indep
+            start + offset
         }
     }
 }
 
-impl<Indep> Bound<Indep> for Interp {
-    fn upper_bound(indep: Indep, _upper_bound: Indep) -> Indep {
-        indep
+/// Determine bounding behavior dynamically at runtime, so that one table type can be configured
+/// with any bounding behavior.
+///
+/// # Example
+///
+/// ```
+/// use lookup_tables::{Axis, Binary, LookupError, LookupTable1D, RuntimeBound};
+///
+/// type RuntimeAxis = Axis<f64, Binary, RuntimeBound, RuntimeBound>;
+/// type RuntimeTable = LookupTable1D<RuntimeAxis, f64>;
+///
+/// // read from a configuration file
+/// fn bound(name: &str) -> RuntimeBound {
+///     match name {
+///         "clamp" => RuntimeBound::clamp(),
+///         "extrapolate" => RuntimeBound::interp(),
+///         "fill" => RuntimeBound::fill(0.),
+///         _ => RuntimeBound::reject(),
+///     }
+/// }
+///
+/// let table = |lower, upper| {
+///     RuntimeTable::with_bounds(vec![0., 1.], Binary::new(), bound(lower), bound(upper), vec![0., 10.])
+///         .unwrap()
+/// };
+///
+/// let clamp_fill = table("clamp", "fill");
+/// assert!(clamp_fill.lookup(-1.) == 0.);
+/// assert!(clamp_fill.lookup(2.) == 0.);
+///
+/// let extrapolate_reject = table("extrapolate", "reject");
+/// assert!(extrapolate_reject.lookup(-1.) == -10.);
+/// assert!(matches!(extrapolate_reject.try_lookup(2.), Err(LookupError::OutOfBounds { .. })));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub enum RuntimeBound<Indep = f64, Dep = f64> {
+    Clamp(Clamp),
+    Interp(Interp),
+    InterpMargin(InterpMargin<Indep>),
+    Reject(Reject),
+    Fill(Fill<Dep>),
+    Periodic(Periodic<Indep>),
+    Reflect(Reflect<Indep>),
+    #[cfg(feature = "num-traits")]
+    LeastSquares(LeastSquares<Indep>),
+}
+
+impl<Indep, Dep> RuntimeBound<Indep, Dep>
+where
+    Indep: Copy,
+{
+    pub fn clamp() -> Self {
+        RuntimeBound::Clamp(Clamp)
+    }
+
+    pub fn interp() -> Self {
+        RuntimeBound::Interp(Interp)
+    }
+
+    pub fn interp_margin(margin: Indep) -> Self {
+        RuntimeBound::InterpMargin(InterpMargin::absolute(margin))
+    }
+
+    pub fn interp_margin_fraction(fraction: Indep) -> Self {
+        RuntimeBound::InterpMargin(InterpMargin::fraction_of_span(fraction))
+    }
+
+    pub fn reject() -> Self {
+        RuntimeBound::Reject(Reject)
+    }
+
+    pub fn fill(value: Dep) -> Self {
+        RuntimeBound::Fill(Fill::new(value))
+    }
+
+    pub fn periodic(period: Indep) -> Self {
+        RuntimeBound::Periodic(Periodic::new(period))
+    }
+
+    pub fn reflect() -> Self {
+        RuntimeBound::Reflect(Reflect::new())
+    }
+
+    pub fn reflect_about(center: Indep) -> Self {
+        RuntimeBound::Reflect(Reflect::about(center))
     }
 
-    fn lower_bound(indep: Indep, _lower_bound: Indep) -> Indep {
-        indep
+    #[cfg(feature = "num-traits")]
+    pub fn least_squares_line(points: usize) -> Self {
+        RuntimeBound::LeastSquares(LeastSquares::line(points))
+    }
+
+    #[cfg(feature = "num-traits")]
+    pub fn least_squares_quadratic(points: usize) -> Self {
+        RuntimeBound::LeastSquares(LeastSquares::quadratic(points))
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Independent variables supported by [RuntimeBound], which are [Float] with the `num-traits`
+/// feature so that [LeastSquares] is available.
+#[cfg(feature = "num-traits")]
+pub trait RuntimeIndep: Float {}
 
-    //
-    // Clamp Tests
-    //
+#[cfg(feature = "num-traits")]
+impl<Indep> RuntimeIndep for Indep where Indep: Float {}
 
-    #[test]
-    fn clamp_inbounds() {
-        let x = 2.5;
-        let lower = 0.0;
-        let higher = 5.0;
-        let output_high = Clamp::upper_bound(x, higher);
-        let output_low = Clamp::lower_bound(x, lower);
+/// Independent variables supported by [RuntimeBound]
+#[cfg(not(feature = "num-traits"))]
+pub trait RuntimeIndep:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Rem<Output = Self>
+{
+}
+
+#[cfg(not(feature = "num-traits"))]
+impl<Indep> RuntimeIndep for Indep where
+    Indep: Copy
+        + PartialOrd
+        + Add<Output = Indep>
+        + Sub<Output = Indep>
+        + Mul<Output = Indep>
+        + Rem<Output = Indep>
+{
+}
+
+/// Result of bounding an independent variable
+#[derive(Debug, Clone, Copy)]
+pub enum Bounded<Indep, Dep> {
+    /// Interpolate the table at this independent variable
+    Indep(Indep),
+    /// Return this dependent variable from the lookup without interpolating
+    Dep(Dep),
+    /// The independent variable is out of bounds. Checked lookups return an error, unchecked lookups
+    /// interpolate at the independent variable given here.
+    Reject(Indep),
+    /// Interpolate the table at this independent variable, and negate the result with the given
+    /// function (typically [Neg::neg]).
+    Negate(Indep, fn(Dep) -> Dep),
+    /// Return a weighted sum of the dependent variables along the axis, instead of interpolating
+    Fit(Fit<Indep>),
+}
 
-        assert!(output_high == x);
-        assert!(output_low == x);
+/// Function pointers cannot be compared reliably, so [Bounded::Negate] compares only the independent
+/// variable.
+impl<Indep, Dep> PartialEq for Bounded<Indep, Dep>
+where
+    Indep: PartialEq,
+    Dep: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Bounded::Indep(a), Bounded::Indep(b)) => a == b,
+            (Bounded::Dep(a), Bounded::Dep(b)) => a == b,
+            (Bounded::Reject(a), Bounded::Reject(b)) => a == b,
+            (Bounded::Negate(a, _), Bounded::Negate(b, _)) => a == b,
+            (Bounded::Fit(a), Bounded::Fit(b)) => a == b,
+            _ => false,
+        }
     }
+}
 
-    #[test]
-    fn clamp_high() {
-        let x = 10.0;
-        let lower = 0.0;
-        let higher = 5.0;
-        let output_high = Clamp::upper_bound(x, higher);
-        let output_low = Clamp::lower_bound(x, lower);
+/// Negation to apply to the result of a lookup, from [Bounded::Negate]
+pub(crate) type Negation<Dep> = Option<fn(Dep) -> Dep>;
+
+/// Weights of a polynomial fit through the independent variables `start..end` of an axis, from
+/// [LeastSquares](crate::LeastSquares).
+///
+/// The weight of each independent variable is a polynomial in its offset from `center`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fit<Indep> {
+    start: usize,
+    end: usize,
+    center: Indep,
+    coefficients: [Indep; 3],
+}
 
-        assert!(output_high == higher);
-        assert!(output_low == x);
+impl<Indep> Fit<Indep>
+where
+    Indep: Copy + Add<Output = Indep> + Sub<Output = Indep> + Mul<Output = Indep>,
+{
+    /// Index and weight of each dependent variable of the fit
+    pub(crate) fn weights(
+        self,
+        indep_values: &[Indep],
+    ) -> impl Iterator<Item = (usize, Indep)> + '_ {
+        let [constant, linear, quadratic] = self.coefficients;
+
+        (self.start..self.end).map(move |index| {
+            let offset = indep_values[index] - self.center;
+            (index, constant + (linear + quadratic * offset) * offset)
+        })
     }
+}
 
-    #[test]
-    fn clamp_low() {
-        let x = -2.0;
-        let lower = 0.0;
-        let higher = 5.0;
-        let output_high = Clamp::upper_bound(x, higher);
-        let output_low = Clamp::lower_bound(x, lower);
+/// Position of a lookup along one axis of a table, after it has been bounded and searched
+#[derive(Clone, Copy)]
+pub(crate) enum Stencil<Indep> {
+    /// Interpolate at the independent variable, between the pair of indices
+    Interpolate(Indep, (usize, usize)),
+    /// Weighted sum of a fit
+    Fit(Fit<Indep>),
+}
+
+// only used by tables with multiple axes
+#[cfg(feature = "ndarray")]
+impl<Indep> Stencil<Indep>
+where
+    Indep: Copy
+        + Add<Output = Indep>
+        + Sub<Output = Indep>
+        + Mul<Output = Indep>
+        + std::ops::Div<Output = Indep>,
+{
+    /// Index and weight of each dependent variable of the stencil
+    pub(crate) fn weights(
+        self,
+        indep_values: &[Indep],
+    ) -> impl Iterator<Item = (usize, Indep)> + '_ {
+        let interpolate = match self {
+            Stencil::Interpolate(indep, (idx_l, idx_h)) => {
+                let indep_l = indep_values[idx_l];
+                let indep_h = indep_values[idx_h];
+
+                Some([
+                    (idx_l, (indep_h - indep) / (indep_h - indep_l)),
+                    (idx_h, (indep - indep_l) / (indep_h - indep_l)),
+                ])
+            }
+            Stencil::Fit(_) => None,
+        };
+
+        let fit = match self {
+            Stencil::Fit(fit) => Some(fit.weights(indep_values)),
+            Stencil::Interpolate(..) => None,
+        };
+
+        interpolate
+            .into_iter()
+            .flatten()
+            .chain(fit.into_iter().flatten())
+    }
+}
+
+impl<Indep> Stencil<Indep>
+where
+    Indep: Copy,
+{
+    /// Move the position of an interpolating stencil between its pair of grid points, with the
+    /// interpolation kind of the axis
+    pub(crate) fn position<Interpolation>(self, indep_values: &[Indep]) -> Self
+    where
+        Interpolation: interpolation::Interpolation<Indep>,
+    {
+        match self {
+            Stencil::Interpolate(indep, (idx_l, idx_h)) => {
+                let indep =
+                    Interpolation::position(indep, indep_values[idx_l], indep_values[idx_h]);
+                Stencil::Interpolate(indep, (idx_l, idx_h))
+            }
+            Stencil::Fit(fit) => Stencil::Fit(fit),
+        }
+    }
+}
+
+/// Lookup along one axis which ends before the table is evaluated, from [stencil]
+pub(crate) enum Early<Dep> {
+    /// The bound returned this dependent variable in place of the lookup
+    Dep(Dep),
+    /// The bound rejected the independent variable of a checked lookup
+    Reject,
+}
+
+impl<Dep> Early<Dep> {
+    /// Result of an unchecked lookup, which never rejects values
+    pub(crate) fn unchecked(self) -> Dep {
+        match self {
+            Early::Dep(dep) => dep,
+            Early::Reject => unreachable!("unchecked lookups interpolate rejected values"),
+        }
+    }
+
+    /// Result of a checked lookup, with the error `reject` for rejected values
+    pub(crate) fn checked<E>(self, reject: impl FnOnce() -> E) -> Result<Dep, E> {
+        match self {
+            Early::Dep(dep) => Ok(dep),
+            Early::Reject => Err(reject()),
+        }
+    }
+}
+
+/// Stencil for a bounded independent variable, finding the pair of indices to interpolate between
+/// with `search`. Rejected values are interpolated like any other unless the lookup is `checked`.
+pub(crate) fn stencil<Indep, Dep, F>(
+    bounded: Bounded<Indep, Dep>,
+    checked: bool,
+    search: F,
+) -> Result<Stencil<Indep>, Early<Dep>>
+where
+    Indep: Copy,
+    F: FnOnce(Indep) -> (usize, usize),
+{
+    match bounded {
+        Bounded::Reject(_) if checked => Err(Early::Reject),
+        Bounded::Indep(indep) | Bounded::Negate(indep, _) | Bounded::Reject(indep) => {
+            Ok(Stencil::Interpolate(indep, search(indep)))
+        }
+        Bounded::Fit(fit) => Ok(Stencil::Fit(fit)),
+        Bounded::Dep(dep) => Err(Early::Dep(dep)),
+    }
+}
+
+/// Sum the terms of a weighted sum of dependent variables
+pub(crate) fn sum<Dep>(mut terms: impl Iterator<Item = Dep>) -> Dep
+where
+    Dep: Add<Output = Dep>,
+{
+    // stencils always contain at least two dependent variables
+    let first = terms.next().unwrap();
+    terms.fold(first, |sum, term| sum + term)
+}
+
+/// Defines how to treat a lookup of an independent variable its upper and lower bounds.
+///
+/// Bounds are stored in the table, so they may carry parameters of their own. Tables are constructed
+/// with default bounds by `new`, or with the provided bounds by `with_bounds`.
+///
+/// Implementations only need [upper_bound](Self::upper_bound) and
+/// [lower_bound](Self::lower_bound), which return a [Bounded] describing how the table continues
+/// the lookup: interpolating at an independent variable, returning a dependent variable, rejecting
+/// the lookup, negating the result, or evaluating a fit. Bounds which depend on the independent
+/// variable of the table compute their state in [prepare](Self::prepare), and periodic bounds
+/// extend the table with [periodic_seam](Self::periodic_seam).
+///
+/// # Example
+///
+/// ```
+/// use lookup_tables::{Axis, Bound, Bounded, Clamp, Linear, LookupTable1D};
+///
+/// /// Never look up values above `ceiling`
+/// struct Ceiling {
+///     ceiling: f64,
+/// }
+///
+/// impl<Dep> Bound<f64, Dep> for Ceiling {
+///     fn upper_bound(&self, indep: f64, _upper_bound: f64) -> Bounded<f64, Dep> {
+///         Bounded::Indep(indep.min(self.ceiling))
+///     }
+///
+///     fn lower_bound(&self, indep: f64, _lower_bound: f64) -> Bounded<f64, Dep> {
+///         Bounded::Indep(indep.min(self.ceiling))
+///     }
+/// }
+///
+/// type CeilingAxis = Axis<f64, Linear, Clamp, Ceiling>;
+///
+/// let x = vec![0., 1., 2.];
+/// let y = vec![0., 10., 20.];
+///
+/// let table = LookupTable1D::<CeilingAxis, f64>::with_bounds(
+///     x,
+///     Linear::new(),
+///     Clamp,
+///     Ceiling { ceiling: 3.0 },
+///     y,
+/// )
+/// .unwrap();
+///
+/// assert!(table.lookup(2.5) == 25.);
+/// assert!(table.lookup(10.) == 30.);
+/// ```
+pub trait Bound<Indep, Dep> {
+    /// Behavior at the upper bound.
+    fn upper_bound(&self, indep: Indep, upper_bound: Indep) -> Bounded<Indep, Dep>;
+
+    /// Behavior at the lower bound.
+    fn lower_bound(&self, indep: Indep, lower_bound: Indep) -> Bounded<Indep, Dep>;
+
+    /// Prepare the bound for the (increasing) independent variable of the table when it is
+    /// constructed.
+    fn prepare(&mut self, indep_values: &[Indep]) -> Result<(), Error> {
+        let _ = indep_values;
+        Ok(())
+    }
+
+    /// Entry one period above the first entry of the (increasing) independent variable, for bounds
+    /// which wrap lookups periodically, such as [Periodic]. When the table is constructed, the
+    /// entry is appended to the independent variable with the dependent variable of the first
+    /// entry, unless the independent variable already ends with it. Both bounds of an axis must
+    /// return the same entry, or neither.
+    fn periodic_seam(&self, indep_values: &[Indep]) -> Result<Option<Indep>, Error> {
+        let _ = indep_values;
+        Ok(None)
+    }
+}
+
+impl<Indep, Dep> Bound<Indep, Dep> for Clamp
+where
+    Indep: std::cmp::PartialOrd,
+{
+    fn upper_bound(&self, indep: Indep, upper_bound: Indep) -> Bounded<Indep, Dep> {
+        // dont use std::cmp::max here because it requires Ord, which floats dont have
+        if indep > upper_bound {
+            Bounded::Indep(upper_bound)
+        } else {
+            Bounded::Indep(indep)
+        }
+    }
+
+    fn lower_bound(&self, indep: Indep, lower_bound: Indep) -> Bounded<Indep, Dep> {
+        // dont use std::cmp::min here because it requires Ord, which floats dont have
+        if indep < lower_bound {
+            Bounded::Indep(lower_bound)
+        } else {
+            Bounded::Indep(indep)
+        }
+    }
+}
+
+impl<Indep, Dep> Bound<Indep, Dep> for Reject
+where
+    Indep: std::cmp::PartialOrd,
+{
+    fn upper_bound(&self, indep: Indep, upper_bound: Indep) -> Bounded<Indep, Dep> {
+        if indep > upper_bound {
+            Bounded::Reject(upper_bound)
+        } else {
+            Bounded::Indep(indep)
+        }
+    }
+
+    fn lower_bound(&self, indep: Indep, lower_bound: Indep) -> Bounded<Indep, Dep> {
+        if indep < lower_bound {
+            Bounded::Reject(lower_bound)
+        } else {
+            Bounded::Indep(indep)
+        }
+    }
+}
+
+impl<Indep, Dep> Bound<Indep, Dep> for Interp {
+    fn upper_bound(&self, indep: Indep, _upper_bound: Indep) -> Bounded<Indep, Dep> {
+        Bounded::Indep(indep)
+    }
+
+    fn lower_bound(&self, indep: Indep, _lower_bound: Indep) -> Bounded<Indep, Dep> {
+        Bounded::Indep(indep)
+    }
+}
+
+impl<Indep, Dep> Bound<Indep, Dep> for Fill<Dep>
+where
+    Indep: std::cmp::PartialOrd,
+    Dep: Copy,
+{
+    fn upper_bound(&self, indep: Indep, upper_bound: Indep) -> Bounded<Indep, Dep> {
+        if indep > upper_bound {
+            Bounded::Dep(self.value)
+        } else {
+            Bounded::Indep(indep)
+        }
+    }
+
+    fn lower_bound(&self, indep: Indep, lower_bound: Indep) -> Bounded<Indep, Dep> {
+        if indep < lower_bound {
+            Bounded::Dep(self.value)
+        } else {
+            Bounded::Indep(indep)
+        }
+    }
+}
+
+impl<Indep, Dep> Bound<Indep, Dep> for Periodic<Indep>
+where
+    Indep: Copy + PartialOrd + Add<Output = Indep> + Sub<Output = Indep> + Rem<Output = Indep>,
+{
+    fn upper_bound(&self, indep: Indep, upper_bound: Indep) -> Bounded<Indep, Dep> {
+        // the upper bound of the table is the appended seam, one period above the lower bound
+        Bounded::Indep(self.wrap(indep, upper_bound - self.period))
+    }
+
+    fn lower_bound(&self, indep: Indep, lower_bound: Indep) -> Bounded<Indep, Dep> {
+        Bounded::Indep(self.wrap(indep, lower_bound))
+    }
+
+    fn periodic_seam(&self, indep_values: &[Indep]) -> Result<Option<Indep>, Error> {
+        // unwrap is safe here as tables have checked the length is at least two
+        let first = *indep_values.first().unwrap();
+        let last = *indep_values.last().unwrap();
+        let seam = first + self.period;
+
+        if seam < last {
+            Err(Error::PeriodTooShort)
+        } else {
+            Ok(Some(seam))
+        }
+    }
+}
+
+impl<Indep, Dep> Bound<Indep, Dep> for InterpMargin<Indep>
+where
+    Indep: Copy + PartialOrd + Add<Output = Indep> + Sub<Output = Indep> + Mul<Output = Indep>,
+{
+    fn upper_bound(&self, indep: Indep, upper_bound: Indep) -> Bounded<Indep, Dep> {
+        let limit = upper_bound + self.margin;
+
+        if indep > limit {
+            Bounded::Indep(limit)
+        } else {
+            Bounded::Indep(indep)
+        }
+    }
+
+    fn lower_bound(&self, indep: Indep, lower_bound: Indep) -> Bounded<Indep, Dep> {
+        let limit = lower_bound - self.margin;
+
+        if indep < limit {
+            Bounded::Indep(limit)
+        } else {
+            Bounded::Indep(indep)
+        }
+    }
+
+    fn prepare(&mut self, indep_values: &[Indep]) -> Result<(), Error> {
+        // unwrap is safe here as tables have checked the length is at least two
+        let first = *indep_values.first().unwrap();
+        let last = *indep_values.last().unwrap();
+
+        if let Some(fraction) = self.span_fraction {
+            self.margin = fraction * (last - first);
+        }
+
+        if first + self.margin < first {
+            return Err(Error::NegativeMargin);
+        }
+
+        Ok(())
+    }
+}
+
+impl<Indep, Dep> Bound<Indep, Dep> for RuntimeBound<Indep, Dep>
+where
+    Indep: RuntimeIndep,
+    Dep: Copy + Neg<Output = Dep>,
+{
+    fn upper_bound(&self, indep: Indep, upper_bound: Indep) -> Bounded<Indep, Dep> {
+        match self {
+            RuntimeBound::Clamp(x) => x.upper_bound(indep, upper_bound),
+            RuntimeBound::Interp(x) => x.upper_bound(indep, upper_bound),
+            RuntimeBound::InterpMargin(x) => x.upper_bound(indep, upper_bound),
+            RuntimeBound::Reject(x) => x.upper_bound(indep, upper_bound),
+            RuntimeBound::Fill(x) => x.upper_bound(indep, upper_bound),
+            RuntimeBound::Periodic(x) => x.upper_bound(indep, upper_bound),
+            RuntimeBound::Reflect(x) => x.upper_bound(indep, upper_bound),
+            #[cfg(feature = "num-traits")]
+            RuntimeBound::LeastSquares(x) => x.upper_bound(indep, upper_bound),
+        }
+    }
+
+    fn lower_bound(&self, indep: Indep, lower_bound: Indep) -> Bounded<Indep, Dep> {
+        match self {
+            RuntimeBound::Clamp(x) => x.lower_bound(indep, lower_bound),
+            RuntimeBound::Interp(x) => x.lower_bound(indep, lower_bound),
+            RuntimeBound::InterpMargin(x) => x.lower_bound(indep, lower_bound),
+            RuntimeBound::Reject(x) => x.lower_bound(indep, lower_bound),
+            RuntimeBound::Fill(x) => x.lower_bound(indep, lower_bound),
+            RuntimeBound::Periodic(x) => x.lower_bound(indep, lower_bound),
+            RuntimeBound::Reflect(x) => x.lower_bound(indep, lower_bound),
+            #[cfg(feature = "num-traits")]
+            RuntimeBound::LeastSquares(x) => x.lower_bound(indep, lower_bound),
+        }
+    }
+
+    fn prepare(&mut self, indep_values: &[Indep]) -> Result<(), Error> {
+        match self {
+            RuntimeBound::InterpMargin(x) => Bound::<Indep, Dep>::prepare(x, indep_values),
+            RuntimeBound::Periodic(x) => Bound::<Indep, Dep>::prepare(x, indep_values),
+            #[cfg(feature = "num-traits")]
+            RuntimeBound::LeastSquares(x) => Bound::<Indep, Dep>::prepare(x, indep_values),
+            RuntimeBound::Clamp(_)
+            | RuntimeBound::Interp(_)
+            | RuntimeBound::Reject(_)
+            | RuntimeBound::Fill(_)
+            | RuntimeBound::Reflect(_) => Ok(()),
+        }
+    }
+
+    fn periodic_seam(&self, indep_values: &[Indep]) -> Result<Option<Indep>, Error> {
+        match self {
+            RuntimeBound::Periodic(x) => Bound::<Indep, Dep>::periodic_seam(x, indep_values),
+            RuntimeBound::Clamp(_)
+            | RuntimeBound::Interp(_)
+            | RuntimeBound::InterpMargin(_)
+            | RuntimeBound::Reject(_)
+            | RuntimeBound::Fill(_)
+            | RuntimeBound::Reflect(_) => Ok(None),
+            #[cfg(feature = "num-traits")]
+            RuntimeBound::LeastSquares(_) => Ok(None),
+        }
+    }
+}
+
+impl<Indep, Dep> Bound<Indep, Dep> for Reflect<Indep>
+where
+    Indep: Copy + PartialOrd + Add<Output = Indep> + Sub<Output = Indep>,
+    Dep: Neg<Output = Dep>,
+{
+    fn upper_bound(&self, indep: Indep, upper_bound: Indep) -> Bounded<Indep, Dep> {
+        let center = self.center.unwrap_or(upper_bound);
+
+        if indep > center {
+            self.reflect(indep, center)
+        } else {
+            Bounded::Indep(indep)
+        }
+    }
+
+    fn lower_bound(&self, indep: Indep, lower_bound: Indep) -> Bounded<Indep, Dep> {
+        let center = self.center.unwrap_or(lower_bound);
+
+        if indep < center {
+            self.reflect(indep, center)
+        } else {
+            Bounded::Indep(indep)
+        }
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<Indep, Dep> Bound<Indep, Dep> for LeastSquares<Indep>
+where
+    Indep: Float,
+{
+    fn upper_bound(&self, indep: Indep, upper_bound: Indep) -> Bounded<Indep, Dep> {
+        match &self.upper {
+            Some(normal_equations) if indep > upper_bound => {
+                Bounded::Fit(self.fit(normal_equations, indep))
+            }
+            _ => Bounded::Indep(indep),
+        }
+    }
+
+    fn lower_bound(&self, indep: Indep, lower_bound: Indep) -> Bounded<Indep, Dep> {
+        match &self.lower {
+            Some(normal_equations) if indep < lower_bound => {
+                Bounded::Fit(self.fit(normal_equations, indep))
+            }
+            _ => Bounded::Indep(indep),
+        }
+    }
+
+    fn prepare(&mut self, indep_values: &[Indep]) -> Result<(), Error> {
+        if self.points <= self.degree || self.points > indep_values.len() {
+            return Err(Error::FitPoints);
+        }
+
+        // unwrap is safe here as tables have checked the length is at least two
+        let first = *indep_values.first().unwrap();
+        let last = *indep_values.last().unwrap();
+
+        self.lower = Some(self.normal_equations(indep_values, 0, first));
+        self.upper =
+            Some(self.normal_equations(indep_values, indep_values.len() - self.points, last));
+
+        Ok(())
+    }
+}
+
+/// Entry to append to the independent variable of an axis with periodic bounds, if it does not
+/// already end with the seam. A periodic axis must be periodic on both sides with the same period.
+pub(crate) fn periodic_seam<Indep, Dep, LowerBound, UpperBound>(
+    lower_bound: &LowerBound,
+    upper_bound: &UpperBound,
+    indep_values: &[Indep],
+) -> Result<Option<Indep>, Error>
+where
+    Indep: PartialOrd,
+    LowerBound: Bound<Indep, Dep>,
+    UpperBound: Bound<Indep, Dep>,
+{
+    let lower = lower_bound.periodic_seam(indep_values)?;
+    let upper = upper_bound.periodic_seam(indep_values)?;
+
+    let seam = match (lower, upper) {
+        (None, None) => return Ok(None),
+        (Some(lower), Some(upper)) if lower == upper => lower,
+        _ => return Err(Error::PeriodMismatch),
+    };
+
+    // unwrap is safe here as tables have checked the length is at least two
+    if &seam > indep_values.last().unwrap() {
+        Ok(Some(seam))
+    } else {
+        // the table already contains the seam
+        Ok(None)
+    }
+}
+
+/// Apply the lower and then the upper bound of an axis to `indep`.
+///
+/// The returned [Bounded] is never [Bounded::Negate]. Negations are returned separately, so they
+/// also apply to values rejected or fit after being reflected. Negations of values filled after
+/// being reflected are applied to the returned [Bounded::Dep].
+pub(crate) fn apply<Indep, Dep, LowerBound, UpperBound>(
+    lower_bound: &LowerBound,
+    upper_bound: &UpperBound,
+    indep: Indep,
+    indep_values: &[Indep],
+) -> (Bounded<Indep, Dep>, Negation<Dep>)
+where
+    Indep: Copy,
+    LowerBound: Bound<Indep, Dep>,
+    UpperBound: Bound<Indep, Dep>,
+{
+    // unwrap is safe here as tables have checked the length is at least two
+    let lower = *indep_values.first().unwrap();
+    let upper = *indep_values.last().unwrap();
+
+    let mut negation = None;
+
+    let (indep, rejected) = match lower_bound.lower_bound(indep, lower) {
+        Bounded::Indep(indep) => (indep, false),
+        Bounded::Reject(indep) => (indep, true),
+        Bounded::Negate(indep, negate) => {
+            negation = Some(negate);
+            (indep, false)
+        }
+        Bounded::Fit(fit) => return (Bounded::Fit(fit), negation),
+        Bounded::Dep(dep) => return (Bounded::Dep(dep), None),
+    };
+
+    let (indep, rejected) = match upper_bound.upper_bound(indep, upper) {
+        Bounded::Indep(indep) => (indep, rejected),
+        Bounded::Reject(indep) => (indep, true),
+        Bounded::Negate(indep, negate) => {
+            // negating twice cancels out
+            negation = match negation {
+                Some(_) => None,
+                None => Some(negate),
+            };
+            (indep, rejected)
+        }
+        Bounded::Fit(fit) => return (Bounded::Fit(fit), negation),
+        // the value of a reflected lookup filled by the upper bound is negated like any other
+        Bounded::Dep(dep) => return (Bounded::Dep(negate(&[negation], dep)), None),
+    };
+
+    if rejected {
+        (Bounded::Reject(indep), negation)
+    } else {
+        (Bounded::Indep(indep), negation)
+    }
+}
+
+/// Apply the negations of each axis to the result of a lookup
+pub(crate) fn negate<Dep>(negations: &[Negation<Dep>], dep: Dep) -> Dep {
+    negations
+        .iter()
+        .flatten()
+        .fold(dep, |dep, negate| negate(dep))
+}
+
+/// Error for `value` rejected by a bound of `axis`, with values of the independent variable
+/// converted by `output`
+pub(crate) fn out_of_bounds<Indep, Output>(
+    axis: usize,
+    value: Indep,
+    indep_values: &[Indep],
+    output: impl Fn(Indep) -> Output,
+) -> LookupError<Output>
+where
+    Indep: Copy,
+{
+    // unwrap is safe here as tables have checked the length is at least two
+    LookupError::OutOfBounds {
+        axis,
+        value: output(value),
+        lower: output(*indep_values.first().unwrap()),
+        upper: output(*indep_values.last().unwrap()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //
+    // Clamp Tests
+    //
+
+    #[test]
+    fn clamp_inbounds() {
+        let x = 2.5;
+        let lower = 0.0;
+        let higher = 5.0;
+        let output_high: Bounded<_, f64> = Clamp.upper_bound(x, higher);
+        let output_low: Bounded<_, f64> = Clamp.lower_bound(x, lower);
+
+        assert!(output_high == Bounded::Indep(x));
+        assert!(output_low == Bounded::Indep(x));
+    }
+
+    #[test]
+    fn clamp_high() {
+        let x = 10.0;
+        let lower = 0.0;
+        let higher = 5.0;
+        let output_high: Bounded<_, f64> = Clamp.upper_bound(x, higher);
+        let output_low: Bounded<_, f64> = Clamp.lower_bound(x, lower);
+
+        assert!(output_high == Bounded::Indep(higher));
+        assert!(output_low == Bounded::Indep(x));
+    }
+
+    #[test]
+    fn clamp_low() {
+        let x = -2.0;
+        let lower = 0.0;
+        let higher = 5.0;
+        let output_high: Bounded<_, f64> = Clamp.upper_bound(x, higher);
+        let output_low: Bounded<_, f64> = Clamp.lower_bound(x, lower);
+
+        assert!(output_high == Bounded::Indep(x));
+        assert!(output_low == Bounded::Indep(lower));
+    }
+
+    //
+    // Reject Tests
+    //
+
+    #[test]
+    fn reject() {
+        let output: Bounded<_, f64> = Reject.upper_bound(10.0, 5.0);
+        assert!(output == Bounded::Reject(5.0));
+        let output: Bounded<_, f64> = Reject.lower_bound(-2.0, 0.0);
+        assert!(output == Bounded::Reject(0.0));
+        let output: Bounded<_, f64> = Reject.upper_bound(2.5, 5.0);
+        assert!(output == Bounded::Indep(2.5));
+        let output: Bounded<_, f64> = Reject.lower_bound(2.5, 0.0);
+        assert!(output == Bounded::Indep(2.5));
+    }
+
+    //
+    // Fill Tests
+    //
+
+    #[test]
+    fn fill() {
+        let fill = Fill::new(-1.0);
+        assert!(fill.upper_bound(10.0, 5.0) == Bounded::Dep(-1.0));
+        assert!(fill.lower_bound(-2.0, 0.0) == Bounded::Dep(-1.0));
+        assert!(fill.upper_bound(5.0, 5.0) == Bounded::Indep(5.0));
+        assert!(fill.lower_bound(0.0, 0.0) == Bounded::Indep(0.0));
+    }
+
+    //
+    // InterpMargin Tests
+    //
+
+    #[test]
+    fn interp_margin_absolute() {
+        let mut margin = InterpMargin::absolute(1.0);
+        Bound::<f64, f64>::prepare(&mut margin, &[0.0, 5.0]).unwrap();
+
+        for (x, expected) in [(2.5, 2.5), (5.5, 5.5), (6.5, 6.0)] {
+            let output: Bounded<_, f64> = margin.upper_bound(x, 5.0);
+            assert!(output == Bounded::Indep(expected));
+        }
+
+        for (x, expected) in [(2.5, 2.5), (-0.5, -0.5), (-1.5, -1.0)] {
+            let output: Bounded<_, f64> = margin.lower_bound(x, 0.0);
+            assert!(output == Bounded::Indep(expected));
+        }
+    }
+
+    #[test]
+    fn interp_margin_fraction() {
+        let mut margin = InterpMargin::fraction_of_span(0.5);
+        Bound::<f64, f64>::prepare(&mut margin, &[1.0, 3.0, 5.0]).unwrap();
+
+        let output: Bounded<_, f64> = margin.upper_bound(10.0, 5.0);
+        assert!(output == Bounded::Indep(7.0));
+        let output: Bounded<_, f64> = margin.lower_bound(-10.0, 1.0);
+        assert!(output == Bounded::Indep(-1.0));
+    }
+
+    #[test]
+    fn interp_margin_negative() {
+        let mut margin = InterpMargin::absolute(-1.0);
+        let output = Bound::<f64, f64>::prepare(&mut margin, &[0.0, 5.0]);
+        assert!(matches!(output, Err(Error::NegativeMargin)));
+    }
+
+    //
+    // LeastSquares Tests
+    //
+
+    #[cfg(feature = "num-traits")]
+    fn fit_at(fit: Bounded<f64, f64>, x: &[f64], y: &[f64]) -> f64 {
+        match fit {
+            Bounded::Fit(fit) => fit
+                .weights(x)
+                .map(|(index, weight)| y[index] * weight)
+                .sum(),
+            output => panic!("{output:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn least_squares_line() {
+        let x = [0.0, 1.0, 2.0, 3.0];
+        let mut line = LeastSquares::line(2);
+        Bound::<f64, f64>::prepare(&mut line, &x).unwrap();
+
+        // matches interpolation through the last two points
+        let y = [0.0, 1.0, 4.0, 9.0];
+        assert!(fit_at(line.upper_bound(4.0, 3.0), &x, &y) == 14.0);
+        assert!(fit_at(line.lower_bound(-1.0, 0.0), &x, &y) == -1.0);
+
+        let output: Bounded<_, f64> = line.upper_bound(2.5, 3.0);
+        assert!(output == Bounded::Indep(2.5));
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn least_squares_noisy_line() {
+        let x = [0.0, 1.0, 2.0, 3.0, 4.0];
+        // y = x, with a noisy final interval
+        let y = [0.0, 1.0, 2.0, 3.0, 3.5];
+        let mut line = LeastSquares::line(5);
+        Bound::<f64, f64>::prepare(&mut line, &x).unwrap();
+
+        // least squares slope of 0.9, through the mean (2, 1.9)
+        float_eq::assert_float_eq!(
+            fit_at(line.upper_bound(6.0, 4.0), &x, &y),
+            5.5,
+            abs <= 1e-12
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn least_squares_quadratic() {
+        let x = [0.0, 0.5, 1.5, 3.0, 3.5];
+        let y = x.map(|x| 2.0 * x * x - x + 1.0);
+
+        for points in [3, 4, 5] {
+            let mut quadratic = LeastSquares::quadratic(points);
+            Bound::<f64, f64>::prepare(&mut quadratic, &x).unwrap();
+
+            // quadratics are reproduced exactly
+            for x_out in [4.0, 10.0] {
+                let expected = 2.0 * x_out * x_out - x_out + 1.0;
+                let output = fit_at(quadratic.upper_bound(x_out, 3.5), &x, &y);
+                float_eq::assert_float_eq!(output, expected, abs <= 1e-9);
+            }
+
+            let output = fit_at(quadratic.lower_bound(-2.0, 0.0), &x, &y);
+            float_eq::assert_float_eq!(output, 11.0, abs <= 1e-9);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn least_squares_points() {
+        let x = [0.0, 1.0, 2.0];
+
+        let output = Bound::<f64, f64>::prepare(&mut LeastSquares::line(1), &x);
+        assert!(matches!(output, Err(Error::FitPoints)));
+        let output = Bound::<f64, f64>::prepare(&mut LeastSquares::quadratic(2), &x);
+        assert!(matches!(output, Err(Error::FitPoints)));
+        let output = Bound::<f64, f64>::prepare(&mut LeastSquares::line(4), &x);
+        assert!(matches!(output, Err(Error::FitPoints)));
+        let output = Bound::<f64, f64>::prepare(&mut LeastSquares::quadratic(3), &x);
+        assert!(output.is_ok());
+    }
+
+    //
+    // Reflect Tests
+    //
+
+    #[test]
+    fn reflect() {
+        let reflect = Reflect::new();
+        let output: Bounded<_, f64> = reflect.lower_bound(-2.0, 0.0);
+        assert!(output == Bounded::Indep(2.0));
+        let output: Bounded<_, f64> = reflect.lower_bound(2.0, 0.0);
+        assert!(output == Bounded::Indep(2.0));
+        let output: Bounded<_, f64> = reflect.upper_bound(7.0, 5.0);
+        assert!(output == Bounded::Indep(3.0));
+
+        let reflect = Reflect::about(1.0);
+        let output: Bounded<_, f64> = reflect.lower_bound(-2.0, -10.0);
+        assert!(output == Bounded::Indep(4.0));
+    }
+
+    #[test]
+    fn reflect_antisymmetric() {
+        let reflect = Reflect::new().antisymmetric();
+
+        match reflect.lower_bound(-2.0, 0.0) {
+            Bounded::Negate(indep, negate) => {
+                assert!(indep == 2.0);
+                assert!(negate(3.0) == -3.0);
+            }
+            output => panic!("{output:?}"),
+        }
+
+        let output: Bounded<_, f64> = reflect.lower_bound(2.0, 0.0);
+        assert!(output == Bounded::Indep(2.0));
+    }
+
+    //
+    // Periodic Tests
+    //
+
+    #[test]
+    fn periodic_wrap() {
+        let periodic = Periodic::new(360.0);
+
+        for (x, expected) in [
+            (10.0, 10.0),
+            (370.0, 10.0),
+            (-350.0, 10.0),
+            (-10.0, 350.0),
+            (720.0, 0.0),
+            (359.0, 359.0),
+        ] {
+            let output: Bounded<_, f64> = periodic.lower_bound(x, 0.0);
+            assert!(output == Bounded::Indep(expected), "{x}: {output:?}");
+            let output: Bounded<_, f64> = periodic.upper_bound(x, 360.0);
+            assert!(output == Bounded::Indep(expected), "{x}: {output:?}");
+        }
+
+        // starting from a non-zero lower bound
+        let output: Bounded<_, f64> = periodic.lower_bound(-200.0, -180.0);
+        assert!(output == Bounded::Indep(160.0));
+    }
+
+    #[test]
+    fn periodic_seam_entry() {
+        let periodic = Periodic::new(360.0);
+        let seam = Bound::<f64, f64>::periodic_seam(&periodic, &[0.0, 90.0, 270.0]).unwrap();
+        assert!(seam == Some(360.0));
+
+        let seam = Bound::<f64, f64>::periodic_seam(&periodic, &[0.0, 90.0, 360.0]).unwrap();
+        assert!(seam == Some(360.0));
+        // the table already contains the seam
+        let seam =
+            periodic_seam::<_, f64, _, _>(&periodic, &periodic, &[0.0, 90.0, 360.0]).unwrap();
+        assert!(seam.is_none());
+
+        let seam = Bound::<f64, f64>::periodic_seam(&periodic, &[0.0, 90.0, 400.0]);
+        assert!(matches!(seam, Err(Error::PeriodTooShort)));
+    }
+
+    #[test]
+    fn periodic_seam_mismatch() {
+        let x = [0.0, 90.0, 270.0];
+
+        let seam = periodic_seam::<_, f64, _, _>(&Periodic::new(360.0), &Periodic::new(360.0), &x);
+        assert!(matches!(seam, Ok(Some(360.0))));
+        let seam = periodic_seam::<_, f64, _, _>(&Clamp, &Clamp, &x);
+        assert!(matches!(seam, Ok(None)));
+
+        let seam = periodic_seam::<_, f64, _, _>(&Clamp, &Periodic::new(360.0), &x);
+        assert!(matches!(seam, Err(Error::PeriodMismatch)));
+        let seam = periodic_seam::<_, f64, _, _>(&Periodic::new(360.0), &Clamp, &x);
+        assert!(matches!(seam, Err(Error::PeriodMismatch)));
+        let seam = periodic_seam::<_, f64, _, _>(&Periodic::new(360.0), &Periodic::new(400.0), &x);
+        assert!(matches!(seam, Err(Error::PeriodMismatch)));
+    }
+
+    //
+    // RuntimeBound Tests
+    //
+
+    #[test]
+    fn runtime_bound_matches() {
+        let x = [0.0, 1.0, 5.0];
+
+        let bounds: [(RuntimeBound, Bounded<f64, f64>, _); 6] = [
+            (
+                RuntimeBound::clamp(),
+                Bounded::Indep(0.0),
+                Bounded::Indep(5.0),
+            ),
+            (
+                RuntimeBound::interp(),
+                Bounded::Indep(-10.0),
+                Bounded::Indep(10.0),
+            ),
+            (
+                RuntimeBound::interp_margin_fraction(0.2),
+                Bounded::Indep(-1.0),
+                Bounded::Indep(6.0),
+            ),
+            (
+                RuntimeBound::reject(),
+                Bounded::Reject(0.0),
+                Bounded::Reject(5.0),
+            ),
+            (
+                RuntimeBound::fill(-1.0),
+                Bounded::Dep(-1.0),
+                Bounded::Dep(-1.0),
+            ),
+            (
+                RuntimeBound::periodic(8.0),
+                Bounded::Indep(6.0),
+                Bounded::Indep(2.0),
+            ),
+        ];
+
+        for (mut bound, expected_low, expected_high) in bounds {
+            bound.prepare(&x).unwrap();
+            let seam = bound.periodic_seam(&x).unwrap();
+            let upper = seam.unwrap_or(5.0);
+
+            assert!(bound.lower_bound(-10.0, 0.0) == expected_low, "{bound:?}");
+            assert!(bound.upper_bound(10.0, upper) == expected_high, "{bound:?}");
+            assert!(
+                bound.lower_bound(2.5, 0.0) == Bounded::Indep(2.5),
+                "{bound:?}"
+            );
+            assert!(
+                bound.upper_bound(2.5, upper) == Bounded::Indep(2.5),
+                "{bound:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn runtime_bound_reflect() {
+        let bound: RuntimeBound = RuntimeBound::reflect();
+        assert!(bound.lower_bound(-2.0, 0.0) == Bounded::Indep(2.0));
+        assert!(bound.upper_bound(7.0, 5.0) == Bounded::Indep(3.0));
+
+        let bound: RuntimeBound = RuntimeBound::reflect_about(1.0);
+        assert!(bound.lower_bound(-2.0, 0.0) == Bounded::Indep(4.0));
+
+        let bound: RuntimeBound = RuntimeBound::Reflect(Reflect::new().antisymmetric());
+        let (output, negation) = apply(&bound, &Clamp, -2.0, &[0.0, 1.0, 5.0]);
+        assert!(output == Bounded::Indep(2.0));
+        assert!(negate(&[negation], 1.0) == -1.0);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn runtime_bound_least_squares() {
+        let x = [0.0, 1.0, 2.0, 3.0, 4.0];
+
+        let mut bound: RuntimeBound = RuntimeBound::least_squares_quadratic(3);
+        let mut expected = LeastSquares::quadratic(3);
+        bound.prepare(&x).unwrap();
+        Bound::<f64, f64>::prepare(&mut expected, &x).unwrap();
+
+        let output = bound.lower_bound(-1.0, 0.0);
+        assert!(matches!(output, Bounded::Fit(_)));
+        assert!(output == expected.lower_bound(-1.0, 0.0));
+        assert!(bound.upper_bound(6.0, 4.0) == expected.upper_bound(6.0, 4.0));
+        assert!(bound.upper_bound(2.5, 4.0) == Bounded::Indep(2.5));
+
+        let mut bound: RuntimeBound = RuntimeBound::least_squares_line(6);
+        assert!(matches!(bound.prepare(&x), Err(Error::FitPoints)));
+    }
+
+    //
+    // Apply Tests
+    //
+
+    #[test]
+    fn apply_sides() {
+        let x = [0.0, 1.0, 5.0];
+
+        let (output, _) = apply(&Reject, &Fill::new(-1.0), 2.5, &x);
+        assert!(output == Bounded::Indep(2.5));
+        let (output, _) = apply(&Reject, &Fill::new(-1.0), -2.5, &x);
+        assert!(output == Bounded::Reject(0.0));
+        let (output, _) = apply(&Reject, &Fill::new(-1.0), 7.5, &x);
+        assert!(output == Bounded::Dep(-1.0));
+        let (output, _) = apply(&Clamp, &Reject, 7.5, &x);
+        assert!(output == Bounded::<_, f64>::Reject(5.0));
+    }
+
+    #[test]
+    fn apply_negation() {
+        let x = [0.0, 1.0, 5.0];
+        let reflect = Reflect::new().antisymmetric();
+
+        let (output, negation) = apply(&reflect, &Clamp, -2.5, &x);
+        assert!(output == Bounded::Indep(2.5));
+        assert!(negate(&[negation], 1.0) == -1.0);
+
+        // reflected beyond the upper bound, and rejected
+        let (output, negation) = apply(&reflect, &Reject, -7.5, &x);
+        assert!(output == Bounded::Reject(5.0));
+        assert!(negate(&[negation], 1.0) == -1.0);
+
+        // reflected by both bounds
+        let (output, negation) = apply(&reflect, &Reflect::about(4.0).antisymmetric(), -7.5, &x);
+        assert!(output == Bounded::Indep(0.5));
+        assert!(negate(&[negation], 1.0) == 1.0);
+
+        let (output, negation) = apply(&reflect, &Clamp, 2.5, &x);
+        assert!(output == Bounded::<_, f64>::Indep(2.5));
+        assert!(negation.is_none());
+
+        // reflected beyond the upper bound, and filled
+        let (output, negation) = apply(&reflect, &Fill::new(7.0), -7.5, &x);
+        assert!(output == Bounded::Dep(-7.0));
+        assert!(negation.is_none());
+    }
+
+    #[test]
+    fn out_of_bounds_error() {
+        let output = out_of_bounds(2, 6.0f32, &[0.0f32, 5.0], f64::from);
+        assert!(
+            output
+                == LookupError::OutOfBounds {
+                    axis: 2,
+                    value: 6.0,
+                    lower: 0.0,
+                    upper: 5.0
+                }
+        );
+    }
+
+    #[test]
+    fn stencil_checked() {
+        let search = |_| (0, 1);
 
-        assert!(output_high == x);
-        assert!(output_low == lower);
+        let output = stencil(Bounded::<_, f64>::Reject(0.0), false, search);
+        assert!(matches!(output, Ok(Stencil::Interpolate(0.0, (0, 1)))));
+        let output = stencil(Bounded::<_, f64>::Reject(0.0), true, search);
+        assert!(matches!(output, Err(Early::Reject)));
+        let output = stencil(Bounded::Dep(3.0), false, search);
+        assert!(output.map_err(Early::unchecked).err() == Some(3.0));
+        let output = stencil(Bounded::Dep(3.0), true, search);
+        assert!(output.map_err(|early| early.checked(|| ())).err() == Some(Ok(3.0)));
     }
 
     //
@@ -146,11 +1746,11 @@ mod tests {
         let x = 2.5;
         let lower = 0.0;
         let higher = 5.0;
-        let output_high = Interp::upper_bound(x, higher);
-        let output_low = Interp::lower_bound(x, lower);
+        let output_high: Bounded<_, f64> = Interp.upper_bound(x, higher);
+        let output_low: Bounded<_, f64> = Interp.lower_bound(x, lower);
 
-        assert!(output_high == x);
-        assert!(output_low == x);
+        assert!(output_high == Bounded::Indep(x));
+        assert!(output_low == Bounded::Indep(x));
     }
 
     #[test]
@@ -158,11 +1758,11 @@ mod tests {
         let x = 10.0;
         let lower = 0.0;
         let higher = 5.0;
-        let output_high = Interp::upper_bound(x, higher);
-        let output_low = Interp::lower_bound(x, lower);
+        let output_high: Bounded<_, f64> = Interp.upper_bound(x, higher);
+        let output_low: Bounded<_, f64> = Interp.lower_bound(x, lower);
 
-        assert!(output_high == x);
-        assert!(output_low == x);
+        assert!(output_high == Bounded::Indep(x));
+        assert!(output_low == Bounded::Indep(x));
     }
 
     #[test]
@@ -170,10 +1770,10 @@ mod tests {
         let x = -2.0;
         let lower = 0.0;
         let higher = 5.0;
-        let output_high = Interp::upper_bound(x, higher);
-        let output_low = Interp::lower_bound(x, lower);
+        let output_high: Bounded<_, f64> = Interp.upper_bound(x, higher);
+        let output_low: Bounded<_, f64> = Interp.lower_bound(x, lower);
 
-        assert!(output_high == x);
-        assert!(output_low == x);
+        assert!(output_high == Bounded::Indep(x));
+        assert!(output_low == Bounded::Indep(x));
     }
 }
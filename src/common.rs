@@ -19,28 +19,28 @@ where
 
     // check if mono increasing
     if indep.is_sorted() {
-        return Ok(IndependentVariableOrdering::MonotonicallyIncreasing);
+        Ok(IndependentVariableOrdering::MonotonicallyIncreasing)
     } else {
         // check if monotonically decreasing
         if indep.is_sorted_by(|l, r| r < l) {
-            return Ok(IndependentVariableOrdering::MonotonicallyDecreasing);
+            Ok(IndependentVariableOrdering::MonotonicallyDecreasing)
         }
         // its neither increasing or decreasing, there is an error
         else {
-            return Err(Error::NonMonotonicSorting);
-            //
+            Err(Error::NonMonotonicSorting)
         }
     }
 }
 
+/// Check for entries appearing more than once. Only adjacent entries are compared, which finds
+/// every duplicate in sorted data in `O(n)`. Duplicates in unsorted data are instead reported as
+/// [Error::NonMonotonicSorting].
 fn check_repeat_entries<Indep>(indep: &[Indep]) -> Result<(), Error>
 where
     Indep: PartialEq<Indep>,
 {
-    for value in indep {
-        if indep.iter().filter(|x| *x == value).count() != 1 {
-            return Err(Error::DuplicateEntry);
-        }
+    if indep.windows(2).any(|pair| pair[0] == pair[1]) {
+        return Err(Error::DuplicateEntry);
     }
     Ok(())
 }
@@ -93,6 +93,22 @@ mod tests {
         assert!(output.is_err());
     }
 
+    #[test]
+    /// duplicates that are not adjacent can only occur in unsorted data
+    fn non_adjacent_repeat_entries() {
+        let entries = [1, 2, 3, 1, 5];
+        let output = check_independent_variable(&entries);
+        assert!(matches!(output, Err(Error::NonMonotonicSorting)));
+    }
+
+    #[test]
+    /// validating an axis is linear in its length, so very large axes are accepted quickly
+    fn large_independent_variable() {
+        let entries: Vec<u32> = (0..1_000_000).collect();
+        let output = check_independent_variable(&entries);
+        assert!(output.is_ok());
+    }
+
     #[test]
     fn monotonically_increasing() {
         let entries = [1, 2, 3, 4, 5];
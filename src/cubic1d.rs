@@ -0,0 +1,917 @@
+use crate::axis;
+use crate::bound;
+use crate::common;
+use crate::interpolation;
+use crate::nan;
+use crate::search;
+use crate::{Error, LookupError};
+use num_traits::Float;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// End conditions of a cubic spline [CubicTable1D]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Cubic<Dep> {
+    /// Cubic spline with zero second derivative at the first and last grid points
+    Natural,
+    /// Cubic spline with the provided first derivatives at the first and last grid points
+    Clamped(Dep, Dep),
+    /// Cubic spline with a continuous third derivative at the second and second to last grid
+    /// points. Tables of three grid points are a parabola, and tables of two grid points are a
+    /// line.
+    NotAKnot,
+}
+
+/// One dimensional cubic lookup table - approximate `f(x)` given `x` with a piecewise cubic
+/// polynomial.
+///
+/// The coefficients of the cubic polynomial between each pair of grid points are computed when the
+/// table is constructed, either as a cubic spline with [new](Self::new), or as a monotone piecewise
+/// cubic Hermite polynomial with [pchip](Self::pchip). Spline tables are twice continuously
+/// differentiable, and PCHIP tables once continuously differentiable. Lookups are bounded
+/// by the [Bound](crate::Bound) types of the axis, with [Interp](crate::Interp) extrapolating the
+/// cubic polynomial of the first / last interval.
+///
+/// The cubic polynomial replaces the interpolation kind of the axis, so tables are only
+/// implemented for axes with the default [LinearInterp](crate::LinearInterp) kind:
+///
+/// ```compile_fail
+/// use lookup_tables::{Axis, Binary, Clamp, Cubic, CubicTable1D, Nearest};
+///
+/// type NearestAxis = Axis<f64, Binary, Clamp, Clamp, Nearest>;
+///
+/// let table = CubicTable1D::<NearestAxis, f64>::new(
+///     vec![0., 1., 2.],
+///     Binary::new(),
+///     vec![0., 1., 4.],
+///     Cubic::Natural,
+/// );
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use lookup_tables::{Axis, Binary, Clamp, Cubic, CubicTable1D};
+///
+/// type BinaryClampAxis = Axis<f64, Binary, Clamp, Clamp>;
+///
+/// // y = x^3
+/// let x = vec![0., 1., 2., 3., 4.];
+/// let y = x.iter().map(|x| x * x * x).collect();
+///
+/// let table =
+///     CubicTable1D::<BinaryClampAxis, f64>::new(x, Binary::new(), y, Cubic::NotAKnot).unwrap();
+///
+/// // not-a-knot splines reproduce cubic polynomials
+/// assert!((table.lookup(2.5) - 15.625).abs() < 1e-12);
+/// // lookups are clamped to the table
+/// assert!((table.lookup(10.) - 64.).abs() < 1e-12);
+/// ```
+pub struct CubicTable1D<Axis: axis::AxisImpl, Dep> {
+    indep: Vec<<Axis as axis::AxisImpl>::Indep>,
+    dep: Vec<Dep>,
+    /// Coefficients of the cubic polynomial of each interval, in increasing powers of the offset
+    /// from the first grid point of the interval
+    coefficients: Vec<[Dep; 4]>,
+    search: <Axis as axis::AxisImpl>::Search,
+    lower_bound: <Axis as axis::AxisImpl>::LowerBound,
+    upper_bound: <Axis as axis::AxisImpl>::UpperBound,
+    nan_policy: nan::NanPolicy<Dep>,
+}
+
+impl<Indep, Search, LowerBound, UpperBound, Dep>
+    CubicTable1D<
+        axis::Axis<Indep, Search, LowerBound, UpperBound, interpolation::LinearInterp>,
+        Dep,
+    >
+where
+    Indep: Float,
+    Search: search::Search<Indep>,
+    LowerBound: bound::Bound<Indep, Dep>,
+    UpperBound: bound::Bound<Indep, Dep>,
+    Dep: Copy
+        + Add<Dep, Output = Dep>
+        + Sub<Dep, Output = Dep>
+        + Mul<Indep, Output = Dep>
+        + Div<Indep, Output = Dep>,
+{
+    /// Construct a new cubic lookup table with the default bounds of the axis. See
+    /// [with_bounds](Self::with_bounds) to provide the bounds.
+    ///
+    /// # Args
+    ///
+    /// ## `indep`
+    ///
+    /// List of independent variables (`x` in `f(x)`).
+    ///
+    /// ## `search`
+    ///
+    /// Search method for `indep`. Implements the [Search](crate::search::Search) trait.
+    ///
+    /// ## `dep`
+    ///
+    /// List of dependent variables (`f(x)`). `Dep` is generally `f64`, `f32` or some vector
+    /// valued `nalgebra::base::Vector`
+    ///
+    /// ## `cubic`
+    ///
+    /// Construction of the slopes of the table at each grid point
+    pub fn new(
+        indep: Vec<Indep>,
+        search: Search,
+        dep: Vec<Dep>,
+        cubic: Cubic<Dep>,
+    ) -> Result<Self, Error>
+    where
+        LowerBound: Default,
+        UpperBound: Default,
+    {
+        Self::with_bounds(
+            indep,
+            search,
+            LowerBound::default(),
+            UpperBound::default(),
+            dep,
+            cubic,
+        )
+    }
+
+    /// Construct a new cubic lookup table with the provided bounds
+    ///
+    /// See [LookupTable1D::with_bounds](crate::LookupTable1D::with_bounds) and [new](Self::new)
+    /// for the arguments.
+    pub fn with_bounds(
+        indep: Vec<Indep>,
+        search: Search,
+        lower_bound: LowerBound,
+        upper_bound: UpperBound,
+        dep: Vec<Dep>,
+        cubic: Cubic<Dep>,
+    ) -> Result<Self, Error> {
+        Self::construct(
+            indep,
+            search,
+            lower_bound,
+            upper_bound,
+            dep,
+            |indep, dep, reversed| match cubic {
+                // end slopes are reversed along with the table
+                Cubic::Clamped(start, end) if reversed => {
+                    spline_slopes(indep, dep, Cubic::Clamped(end, start))
+                }
+                cubic => spline_slopes(indep, dep, cubic),
+            },
+        )
+    }
+
+    /// Construct the table with the slopes at each grid point computed by `slopes`, given the
+    /// increasing independent variable, the dependent variable, and whether the provided data was
+    /// reversed
+    fn construct<F>(
+        mut indep: Vec<Indep>,
+        mut search: Search,
+        mut lower_bound: LowerBound,
+        mut upper_bound: UpperBound,
+        mut dep: Vec<Dep>,
+        slopes: F,
+    ) -> Result<Self, Error>
+    where
+        F: FnOnce(&[Indep], &[Dep], bool) -> Vec<Dep>,
+    {
+        let reversed = match common::check_independent_variable(indep.as_slice())? {
+            common::IndependentVariableOrdering::MonotonicallyIncreasing => false,
+            common::IndependentVariableOrdering::MonotonicallyDecreasing => {
+                indep.reverse();
+                dep.reverse();
+                true
+            }
+        };
+
+        common::check_lengths(indep.len(), dep.len())?;
+
+        if let Some(seam) = bound::periodic_seam(&lower_bound, &upper_bound, indep.as_slice())? {
+            indep.push(seam);
+            dep.push(dep[0]);
+        }
+
+        search.prepare(indep.as_slice())?;
+        lower_bound.prepare(indep.as_slice())?;
+        upper_bound.prepare(indep.as_slice())?;
+
+        let slopes = slopes(indep.as_slice(), dep.as_slice(), reversed);
+        let coefficients = coefficients(indep.as_slice(), dep.as_slice(), slopes.as_slice());
+
+        Ok(Self {
+            indep,
+            dep,
+            coefficients,
+            search,
+            lower_bound,
+            upper_bound,
+            nan_policy: nan::NanPolicy::default(),
+        })
+    }
+
+    /// Set the treatment of NaN inputs to lookups. Defaults to
+    /// [NanPolicy::Propagate](crate::NanPolicy::Propagate).
+    pub fn with_nan_policy(mut self, nan_policy: nan::NanPolicy<Dep>) -> Self {
+        self.nan_policy = nan_policy;
+        self
+    }
+
+    /// Search method of the independent variable
+    pub fn search(&self) -> &Search {
+        &self.search
+    }
+
+    pub fn lookup(&self, x: Indep) -> Dep {
+        if nan::is_nan(&x) {
+            return self.nan_policy.value(|| self.interpolate(x, 0));
+        }
+
+        let (bounded_x, negate_x) = self.bound(x);
+        let x = match bound::stencil(bounded_x, false, |x| {
+            self.search.search(x, self.indep.as_slice())
+        }) {
+            Ok(x) => x,
+            Err(early) => return early.unchecked(),
+        };
+
+        bound::negate(&[negate_x], self.evaluate(x))
+    }
+
+    /// Lookup, returning an error for values beyond a [Reject](crate::Reject) bound, or for NaN
+    /// inputs when the table's [NanPolicy](crate::NanPolicy) is
+    /// [NanPolicy::Error](crate::NanPolicy::Error).
+    pub fn try_lookup(&self, x: Indep) -> Result<Dep, LookupError<Indep>> {
+        if nan::is_nan(&x) {
+            return self.nan_policy.checked(0, || self.interpolate(x, 0));
+        }
+
+        let (bounded_x, negate_x) = self.bound(x);
+        let x = match bound::stencil(bounded_x, true, |x| {
+            self.search.search(x, self.indep.as_slice())
+        }) {
+            Ok(x) => x,
+            Err(early) => {
+                return early.checked(|| bound::out_of_bounds(0, x, self.indep.as_slice(), |x| x))
+            }
+        };
+
+        Ok(bound::negate(&[negate_x], self.evaluate(x)))
+    }
+
+    /// Lookup using the caller owned `cursor` to search the independent variable, instead of the
+    /// table's search method. See [Cursor](crate::Cursor) for more information.
+    pub fn lookup_with_cursor(&self, cursor: &mut search::Cursor, x: Indep) -> Dep {
+        if nan::is_nan(&x) {
+            return self.nan_policy.value(|| self.interpolate(x, 0));
+        }
+
+        let (bounded_x, negate_x) = self.bound(x);
+        let x = match bound::stencil(bounded_x, false, |x| {
+            cursor.search(0, x, self.indep.as_slice())
+        }) {
+            Ok(x) => x,
+            Err(early) => return early.unchecked(),
+        };
+
+        bound::negate(&[negate_x], self.evaluate(x))
+    }
+
+    /// Lookup every value in `xs`, writing the results to the same index of `out`. See
+    /// [LookupTable1D::lookup_sorted_batch](crate::LookupTable1D::lookup_sorted_batch).
+    ///
+    /// # Panics
+    ///
+    /// If `xs` and `out` have different lengths
+    pub fn lookup_sorted_batch(&self, xs: &[Indep], out: &mut [Dep]) {
+        assert_eq!(
+            xs.len(),
+            out.len(),
+            "input and output of a batch lookup must have the same length"
+        );
+
+        let mut merge = search::SortedMerge::default();
+
+        for (x, out) in xs.iter().zip(out.iter_mut()) {
+            if nan::is_nan(x) {
+                *out = self.nan_policy.value(|| self.interpolate(*x, 0));
+                continue;
+            }
+
+            let (bounded_x, negate_x) = self.bound(*x);
+            let x = match bound::stencil(bounded_x, false, |x| {
+                merge.search(&self.search, x, self.indep.as_slice())
+            }) {
+                Ok(x) => x,
+                Err(early) => {
+                    *out = early.unchecked();
+                    continue;
+                }
+            };
+
+            *out = bound::negate(&[negate_x], self.evaluate(x));
+        }
+    }
+
+    fn bound(&self, x: Indep) -> (bound::Bounded<Indep, Dep>, bound::Negation<Dep>) {
+        bound::apply(
+            &self.lower_bound,
+            &self.upper_bound,
+            x,
+            self.indep.as_slice(),
+        )
+    }
+
+    fn evaluate(&self, x: bound::Stencil<Indep>) -> Dep {
+        match x {
+            bound::Stencil::Interpolate(x, (idx_l, _)) => self.interpolate(x, idx_l),
+            bound::Stencil::Fit(fit) => bound::sum(
+                fit.weights(self.indep.as_slice())
+                    .map(|(index, weight)| self.dep[index] * weight),
+            ),
+        }
+    }
+
+    /// Evaluate the cubic polynomial of the interval starting at `interval`
+    fn interpolate(&self, x: Indep, interval: usize) -> Dep {
+        let offset = x - self.indep[interval];
+        let [c0, c1, c2, c3] = self.coefficients[interval];
+
+        c0 + (c1 + (c2 + c3 * offset) * offset) * offset
+    }
+}
+
+impl<Indep, Search, LowerBound, UpperBound, Dep>
+    CubicTable1D<
+        axis::Axis<Indep, Search, LowerBound, UpperBound, interpolation::LinearInterp>,
+        Dep,
+    >
+where
+    Indep: Float,
+    Search: search::Search<Indep>,
+    LowerBound: bound::Bound<Indep, Dep>,
+    UpperBound: bound::Bound<Indep, Dep>,
+    Dep: Float + Mul<Indep, Output = Dep> + Div<Indep, Output = Dep>,
+{
+    /// Construct a new monotone piecewise cubic Hermite (PCHIP) lookup table with the default
+    /// bounds of the axis. See [pchip_with_bounds](Self::pchip_with_bounds) to provide the
+    /// bounds.
+    ///
+    /// The slopes at each grid point are chosen with the Fritsch-Carlson method, so that the
+    /// table does not overshoot the dependent variable between grid points, and monotonic data
+    /// stays monotonic. The slopes follow the method of SciPy's `PchipInterpolator`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lookup_tables::{Axis, Binary, Clamp, CubicTable1D};
+    ///
+    /// type BinaryClampAxis = Axis<f64, Binary, Clamp, Clamp>;
+    ///
+    /// let x = vec![0., 1., 2., 3.];
+    /// let y = vec![0., 0., 1., 1.];
+    ///
+    /// let table = CubicTable1D::<BinaryClampAxis, f64>::pchip(x, Binary::new(), y).unwrap();
+    ///
+    /// // flat sections of the data stay flat
+    /// assert!(table.lookup(0.5) == 0.);
+    /// assert!(table.lookup(1.5) == 0.5);
+    /// assert!(table.lookup(2.5) == 1.);
+    /// ```
+    pub fn pchip(indep: Vec<Indep>, search: Search, dep: Vec<Dep>) -> Result<Self, Error>
+    where
+        LowerBound: Default,
+        UpperBound: Default,
+    {
+        Self::pchip_with_bounds(
+            indep,
+            search,
+            LowerBound::default(),
+            UpperBound::default(),
+            dep,
+        )
+    }
+
+    /// Construct a new monotone piecewise cubic Hermite (PCHIP) lookup table with the provided
+    /// bounds. See [pchip](Self::pchip).
+    pub fn pchip_with_bounds(
+        indep: Vec<Indep>,
+        search: Search,
+        lower_bound: LowerBound,
+        upper_bound: UpperBound,
+        dep: Vec<Dep>,
+    ) -> Result<Self, Error> {
+        Self::construct(
+            indep,
+            search,
+            lower_bound,
+            upper_bound,
+            dep,
+            |indep, dep, _| pchip_slopes(indep, dep),
+        )
+    }
+}
+
+/// Slope of a spline at each grid point
+fn spline_slopes<Indep, Dep>(indep: &[Indep], dep: &[Dep], cubic: Cubic<Dep>) -> Vec<Dep>
+where
+    Indep: Float,
+    Dep: Copy
+        + Add<Dep, Output = Dep>
+        + Sub<Dep, Output = Dep>
+        + Mul<Indep, Output = Dep>
+        + Div<Indep, Output = Dep>,
+{
+    let len = indep.len();
+    let two = Indep::one() + Indep::one();
+    let three = two + Indep::one();
+
+    let widths: Vec<Indep> = indep.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    let secants: Vec<Dep> = dep
+        .windows(2)
+        .zip(widths.iter())
+        .map(|(pair, width)| (pair[1] - pair[0]) / *width)
+        .collect();
+
+    if let Cubic::NotAKnot = cubic {
+        // a not-a-knot spline is a line through two points, and a parabola through three
+        if len == 2 {
+            return vec![secants[0], secants[0]];
+        }
+
+        if len == 3 {
+            let curvature = (secants[1] - secants[0]) / (widths[0] + widths[1]);
+            return vec![
+                secants[0] - curvature * widths[0],
+                secants[0] + curvature * widths[0],
+                secants[1] + curvature * widths[1],
+            ];
+        }
+    }
+
+    // tridiagonal system for the slopes, matching the second derivative of adjacent intervals at
+    // each interior grid point
+    let mut lower = vec![Indep::zero(); len];
+    let mut diagonal = vec![Indep::zero(); len];
+    let mut upper = vec![Indep::zero(); len];
+    let mut rhs = Vec::with_capacity(len);
+
+    let last = len - 1;
+
+    match cubic {
+        Cubic::Natural => {
+            diagonal[0] = two;
+            upper[0] = Indep::one();
+            rhs.push(secants[0] * three);
+        }
+        Cubic::Clamped(start, _) => {
+            diagonal[0] = Indep::one();
+            rhs.push(start);
+        }
+        Cubic::NotAKnot => {
+            let span = widths[0] + widths[1];
+            diagonal[0] = widths[1];
+            upper[0] = span;
+            rhs.push(
+                (secants[0] * ((widths[0] + two * span) * widths[1])
+                    + secants[1] * (widths[0] * widths[0]))
+                    / span,
+            );
+        }
+    }
+
+    for i in 1..len - 1 {
+        lower[i] = widths[i];
+        diagonal[i] = two * (widths[i - 1] + widths[i]);
+        upper[i] = widths[i - 1];
+        rhs.push((secants[i - 1] * widths[i] + secants[i] * widths[i - 1]) * three);
+    }
+
+    match cubic {
+        Cubic::Natural => {
+            lower[last] = Indep::one();
+            diagonal[last] = two;
+            rhs.push(secants[len - 2] * three);
+        }
+        Cubic::Clamped(_, end) => {
+            diagonal[last] = Indep::one();
+            rhs.push(end);
+        }
+        Cubic::NotAKnot => {
+            let (last_width, previous_width) = (widths[len - 2], widths[len - 3]);
+            let span = last_width + previous_width;
+            lower[last] = span;
+            diagonal[last] = previous_width;
+            rhs.push(
+                (secants[len - 3] * (last_width * last_width)
+                    + secants[len - 2] * ((two * span + last_width) * previous_width))
+                    / span,
+            );
+        }
+    }
+
+    solve_tridiagonal(&lower, &diagonal, &upper, rhs)
+}
+
+/// Slope of a monotone piecewise cubic Hermite polynomial at each grid point, following SciPy's
+/// `PchipInterpolator`
+fn pchip_slopes<Indep, Dep>(indep: &[Indep], dep: &[Dep]) -> Vec<Dep>
+where
+    Indep: Float,
+    Dep: Float + Mul<Indep, Output = Dep> + Div<Indep, Output = Dep>,
+{
+    let widths: Vec<Indep> = indep.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    let secants: Vec<Dep> = dep
+        .windows(2)
+        .zip(widths.iter())
+        .map(|(pair, width)| (pair[1] - pair[0]) / *width)
+        .collect();
+
+    // linear interpolation between two points
+    if secants.len() == 1 {
+        return vec![secants[0], secants[0]];
+    }
+
+    let len = indep.len();
+    let two = Indep::one() + Indep::one();
+    let mut slopes = Vec::with_capacity(len);
+
+    slopes.push(pchip_edge(widths[0], widths[1], secants[0], secants[1]));
+
+    for i in 1..len - 1 {
+        let (secant_l, secant_h) = (secants[i - 1], secants[i]);
+
+        // zero slope at local extrema and flat sections
+        if sign(secant_l) != sign(secant_h) || secant_l.is_zero() || secant_h.is_zero() {
+            slopes.push(Dep::zero());
+            continue;
+        }
+
+        // weighted harmonic mean of the secants
+        let weight_l = Dep::one() * (two * widths[i] + widths[i - 1]);
+        let weight_h = Dep::one() * (widths[i] + two * widths[i - 1]);
+        let mean = (weight_l / secant_l + weight_h / secant_h) / (weight_l + weight_h);
+        slopes.push(Dep::one() / mean);
+    }
+
+    slopes.push(pchip_edge(
+        widths[len - 2],
+        widths[len - 3],
+        secants[len - 2],
+        secants[len - 3],
+    ));
+
+    slopes
+}
+
+/// Slope of a monotone piecewise cubic Hermite polynomial at the first or last grid point, from a
+/// three point estimate adjusted to preserve shape. `width` and `secant` are the width and secant of
+/// the interval at the edge, and `width_next` and `secant_next` are of its neighbor.
+fn pchip_edge<Indep, Dep>(width: Indep, width_next: Indep, secant: Dep, secant_next: Dep) -> Dep
+where
+    Indep: Float,
+    Dep: Float + Mul<Indep, Output = Dep> + Div<Indep, Output = Dep>,
+{
+    let two = Indep::one() + Indep::one();
+    let three = Dep::one() + Dep::one() + Dep::one();
+
+    let slope = (secant * (two * width + width_next) - secant_next * width) / (width + width_next);
+
+    if sign(slope) != sign(secant) {
+        Dep::zero()
+    } else if sign(secant) != sign(secant_next) && slope.abs() > three * secant.abs() {
+        three * secant
+    } else {
+        slope
+    }
+}
+
+/// Sign of `value` as `-1`, `0` or `1`
+fn sign<Dep>(value: Dep) -> i8
+where
+    Dep: Float,
+{
+    if value > Dep::zero() {
+        1
+    } else if value < Dep::zero() {
+        -1
+    } else {
+        0
+    }
+}
+
+/// Solve a tridiagonal system with the Thomas algorithm. `lower[0]` and `upper[len - 1]` are
+/// unused.
+fn solve_tridiagonal<Indep, Dep>(
+    lower: &[Indep],
+    diagonal: &[Indep],
+    upper: &[Indep],
+    mut rhs: Vec<Dep>,
+) -> Vec<Dep>
+where
+    Indep: Float,
+    Dep: Copy + Sub<Dep, Output = Dep> + Mul<Indep, Output = Dep> + Div<Indep, Output = Dep>,
+{
+    let len = diagonal.len();
+    let mut upper_prime = vec![Indep::zero(); len];
+
+    upper_prime[0] = upper[0] / diagonal[0];
+    rhs[0] = rhs[0] / diagonal[0];
+
+    for i in 1..len {
+        let pivot = diagonal[i] - lower[i] * upper_prime[i - 1];
+        upper_prime[i] = upper[i] / pivot;
+        rhs[i] = (rhs[i] - rhs[i - 1] * lower[i]) / pivot;
+    }
+
+    for i in (0..len - 1).rev() {
+        rhs[i] = rhs[i] - rhs[i + 1] * upper_prime[i];
+    }
+
+    rhs
+}
+
+/// Coefficients of the cubic polynomial of each interval, from the slopes at each grid point
+fn coefficients<Indep, Dep>(indep: &[Indep], dep: &[Dep], slopes: &[Dep]) -> Vec<[Dep; 4]>
+where
+    Indep: Float,
+    Dep: Copy
+        + Add<Dep, Output = Dep>
+        + Sub<Dep, Output = Dep>
+        + Mul<Indep, Output = Dep>
+        + Div<Indep, Output = Dep>,
+{
+    let two = Indep::one() + Indep::one();
+    let three = two + Indep::one();
+
+    (0..indep.len() - 1)
+        .map(|i| {
+            let width = indep[i + 1] - indep[i];
+            let secant = (dep[i + 1] - dep[i]) / width;
+            let (slope_l, slope_h) = (slopes[i], slopes[i + 1]);
+
+            [
+                dep[i],
+                slope_l,
+                (secant * three - slope_l * two - slope_h) / width,
+                (slope_l + slope_h - secant * two) / (width * width),
+            ]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TOL: f64 = 1e-10;
+
+    type BinaryInterpAxis = axis::Axis<f64, search::Binary, bound::Interp, bound::Interp>;
+    type CubicInterp = CubicTable1D<BinaryInterpAxis, f64>;
+
+    fn cubic(x: f64) -> f64 {
+        x * x * x - 2. * x * x + 0.5 * x + 1.
+    }
+
+    fn cubic_slope(x: f64) -> f64 {
+        3. * x * x - 4. * x + 0.5
+    }
+
+    /// Second derivative of the table on either side of each interior grid point
+    fn second_derivatives(table: &CubicInterp) -> Vec<(f64, f64)> {
+        (1..table.indep.len() - 1)
+            .map(|i| {
+                let [_, _, c2, c3] = table.coefficients[i - 1];
+                let width = table.indep[i] - table.indep[i - 1];
+                let left = 2. * c2 + 6. * c3 * width;
+                let right = 2. * table.coefficients[i][2];
+                (left, right)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn natural() {
+        let table = CubicInterp::new(
+            vec![0., 1., 2.],
+            search::Binary,
+            vec![0., 1., 0.],
+            Cubic::Natural,
+        )
+        .unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(0.), 0., abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(1.), 1., abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(0.5), 0.6875, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(1.5), 0.6875, abs <= TOL);
+
+        // zero curvature at the ends
+        float_eq::assert_float_eq!(table.coefficients[0][2], 0., abs <= TOL);
+        let [_, _, c2, c3] = table.coefficients[1];
+        float_eq::assert_float_eq!(2. * c2 + 6. * c3, 0., abs <= TOL);
+    }
+
+    #[test]
+    fn continuous_second_derivative() {
+        let x = vec![0., 0.3, 1., 1.2, 2.5, 3., 4.1];
+        let y: Vec<f64> = x.iter().map(|x: &f64| x.sin() + 0.1 * x).collect();
+
+        for end in [Cubic::Natural, Cubic::Clamped(1., -1.), Cubic::NotAKnot] {
+            let table = CubicInterp::new(x.clone(), search::Binary, y.clone(), end).unwrap();
+
+            for (x, y) in x.iter().zip(y.iter()) {
+                float_eq::assert_float_eq!(table.lookup(*x), *y, abs <= TOL);
+            }
+            for (left, right) in second_derivatives(&table) {
+                float_eq::assert_float_eq!(left, right, abs <= 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn clamped_reproduces_cubic() {
+        let x = vec![-1., 0., 0.5, 2., 3.];
+        let y = x.iter().copied().map(cubic).collect();
+        let end = Cubic::Clamped(cubic_slope(-1.), cubic_slope(3.));
+        let table = CubicInterp::new(x, search::Binary, y, end).unwrap();
+
+        for x in [-2., -0.5, 0.25, 1., 2.9, 4.] {
+            float_eq::assert_float_eq!(table.lookup(x), cubic(x), abs <= 1e-9);
+        }
+    }
+
+    #[test]
+    fn not_a_knot_reproduces_cubic() {
+        let x = vec![-1., 0., 0.5, 2., 3.];
+        let y = x.iter().copied().map(cubic).collect();
+        let table = CubicInterp::new(x, search::Binary, y, Cubic::NotAKnot).unwrap();
+
+        for x in [-2., -0.5, 0.25, 1., 2.9, 4.] {
+            float_eq::assert_float_eq!(table.lookup(x), cubic(x), abs <= 1e-9);
+        }
+    }
+
+    #[test]
+    fn not_a_knot_short_tables() {
+        let line =
+            CubicInterp::new(vec![0., 2.], search::Binary, vec![1., 5.], Cubic::NotAKnot).unwrap();
+        float_eq::assert_float_eq!(line.lookup(0.5), 2., abs <= TOL);
+        float_eq::assert_float_eq!(line.lookup(3.), 7., abs <= TOL);
+
+        // y = x^2
+        let parabola = CubicInterp::new(
+            vec![0., 1., 3.],
+            search::Binary,
+            vec![0., 1., 9.],
+            Cubic::NotAKnot,
+        )
+        .unwrap();
+        for x in [-1., 0.5, 2., 4.] {
+            float_eq::assert_float_eq!(parabola.lookup(x), x * x, abs <= TOL);
+        }
+    }
+
+    #[test]
+    fn decreasing_clamped() {
+        let x = vec![3., 2., 0.5, 0., -1.];
+        let y = x.iter().copied().map(cubic).collect();
+        // end slopes are given for the first and last entries of the decreasing table
+        let end = Cubic::Clamped(cubic_slope(3.), cubic_slope(-1.));
+        let table = CubicInterp::new(x, search::Binary, y, end).unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(1.), cubic(1.), abs <= 1e-9);
+    }
+
+    #[test]
+    fn bounds_and_lookup_methods() {
+        type ClampAxis = axis::Axis<f64, search::Linear, bound::Clamp, bound::Reject>;
+
+        let x = vec![0., 1., 2., 3.];
+        let y = vec![0., 1., 0., 1.];
+        let table: CubicTable1D<ClampAxis, f64> =
+            CubicTable1D::new(x, search::Linear, y, Cubic::Natural).unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(-1.), 0., abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(4.), 1., abs <= TOL);
+        assert!(matches!(
+            table.try_lookup(4.),
+            Err(LookupError::OutOfBounds { axis: 0, .. })
+        ));
+
+        let xs = [-1., 0.2, 1.5, 1.7, 2.9, 4.];
+        let mut out = [0.; 6];
+        table.lookup_sorted_batch(&xs, &mut out);
+
+        let mut cursor = search::Cursor::new();
+        for (x, output) in xs.iter().zip(out.iter()) {
+            float_eq::assert_float_eq!(*output, table.lookup(*x), abs <= TOL);
+            float_eq::assert_float_eq!(
+                table.lookup_with_cursor(&mut cursor, *x),
+                table.lookup(*x),
+                abs <= TOL
+            );
+        }
+
+        assert!(table.lookup(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn mismatched_lengths() {
+        let table = CubicInterp::new(
+            vec![0., 1., 2.],
+            search::Binary,
+            vec![0., 1.],
+            Cubic::Natural,
+        );
+        assert!(matches!(table, Err(Error::IndependentDependentLength)));
+    }
+
+    //
+    // PCHIP Tests
+    //
+
+    #[test]
+    fn pchip() {
+        let table =
+            CubicInterp::pchip(vec![0., 1., 2., 3.], search::Binary, vec![0., 1., 3., 4.]).unwrap();
+
+        let slopes: Vec<f64> = table.coefficients.iter().map(|c| c[1]).collect();
+        float_eq::assert_float_eq!(slopes[0], 0.5, abs <= TOL);
+        float_eq::assert_float_eq!(slopes[1], 4. / 3., abs <= TOL);
+        float_eq::assert_float_eq!(slopes[2], 4. / 3., abs <= TOL);
+
+        float_eq::assert_float_eq!(table.lookup(0.5), 0.3958333333333333, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(1.5), 2., abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(2.5), 3.6041666666666665, abs <= TOL);
+    }
+
+    #[test]
+    fn pchip_edges() {
+        // the first edge slope is limited to three times the secant, the last is not
+        let table =
+            CubicInterp::pchip(vec![0., 1., 2.], search::Binary, vec![0., 1., -4.]).unwrap();
+
+        let slopes: Vec<f64> = table.coefficients.iter().map(|c| c[1]).collect();
+        float_eq::assert_float_eq!(slopes[0], 3., abs <= TOL);
+        float_eq::assert_float_eq!(slopes[1], 0., abs <= TOL);
+
+        // slope at the last grid point
+        let [_, c1, c2, c3] = table.coefficients[1];
+        float_eq::assert_float_eq!(c1 + 2. * c2 + 3. * c3, -8., abs <= TOL);
+
+        // edge slopes with the opposite sign of the secant are zero
+        let table =
+            CubicInterp::pchip(vec![0., 1., 1.5], search::Binary, vec![0., 1., 3.]).unwrap();
+        float_eq::assert_float_eq!(table.coefficients[0][1], 0., abs <= TOL);
+    }
+
+    #[test]
+    fn pchip_monotone() {
+        let x = vec![0., 1., 1.5, 4., 4.2, 6., 9.];
+        let y = vec![0., 0., 0.1, 5., 5.1, 5.1, 20.];
+        let table = CubicInterp::pchip(x, search::Binary, y).unwrap();
+
+        let mut previous = table.lookup(0.);
+        for i in 1..=900 {
+            let output = table.lookup(i as f64 * 0.01);
+            assert!(output >= previous - 1e-12, "{output} < {previous}");
+            previous = output;
+        }
+
+        // flat sections do not overshoot
+        for x in [0.2, 0.5, 0.8, 4.5, 5.0, 5.8] {
+            let output = table.lookup(x);
+            assert!(output == 0. || (5.1 - 1e-12..=5.1 + 1e-12).contains(&output));
+        }
+    }
+
+    #[test]
+    fn pchip_short_and_decreasing() {
+        let line = CubicInterp::pchip(vec![0., 2.], search::Binary, vec![1., 5.]).unwrap();
+        float_eq::assert_float_eq!(line.lookup(0.5), 2., abs <= TOL);
+        float_eq::assert_float_eq!(line.lookup(3.), 7., abs <= TOL);
+
+        let increasing =
+            CubicInterp::pchip(vec![0., 1., 2., 3.], search::Binary, vec![0., 1., 3., 4.]).unwrap();
+        let decreasing =
+            CubicInterp::pchip(vec![3., 2., 1., 0.], search::Binary, vec![4., 3., 1., 0.]).unwrap();
+        for x in [0.5, 1.5, 2.5] {
+            float_eq::assert_float_eq!(decreasing.lookup(x), increasing.lookup(x), abs <= TOL);
+        }
+    }
+
+    #[test]
+    fn nalgebra_dependent() {
+        let x = vec![0., 1., 2., 3.];
+        let y = x
+            .iter()
+            .map(|x| nalgebra::Vector2::new(cubic(*x), 2. * x))
+            .collect();
+        let table: CubicTable1D<BinaryInterpAxis, nalgebra::Vector2<f64>> =
+            CubicTable1D::new(x, search::Binary, y, Cubic::NotAKnot).unwrap();
+
+        let output = table.lookup(1.5);
+        float_eq::assert_float_eq!(output[0], cubic(1.5), abs <= 1e-9);
+        float_eq::assert_float_eq!(output[1], 3., abs <= 1e-9);
+    }
+}
@@ -0,0 +1,134 @@
+/// Defines how to interpolate between the pair of grid points bounding a lookup.
+///
+/// Interpolation kinds move the position of the lookup within the pair of grid points, and the
+/// table then interpolates linearly at the new position. Step interpolation moves the position to
+/// one of the grid points.
+///
+/// The interpolation kind of an axis is the last type parameter of [Axis](crate::Axis), and
+/// defaults to [LinearInterp]. Each axis of a table may use a different kind.
+///
+/// # Example
+///
+/// ```
+/// use lookup_tables::{Axis, Binary, Clamp, LookupTable2D, Previous};
+///
+/// // step along the gear axis, and interpolate along the speed axis
+/// type GearAxis = Axis<f64, Binary, Clamp, Clamp, Previous>;
+/// type SpeedAxis = Axis<f64, Binary, Clamp, Clamp>;
+///
+/// let gear = vec![1., 2., 3.];
+/// let speed = vec![0., 100.];
+/// let torque = ndarray::array![[0., 300.], [0., 200.], [0., 100.]];
+///
+/// let table = LookupTable2D::<GearAxis, SpeedAxis, f64>::new(
+///     gear,
+///     Binary::new(),
+///     speed,
+///     Binary::new(),
+///     torque,
+/// )
+/// .unwrap();
+///
+/// assert!(table.lookup(1., 50.) == 150.);
+/// assert!(table.lookup(1.9, 50.) == 150.);
+/// assert!(table.lookup(2., 50.) == 100.);
+/// ```
+pub trait Interpolation<Indep> {
+    /// Position to interpolate at, for a lookup of `indep` between the grid points `lower` and
+    /// `upper`. `indep` may be outside of the grid points when the axis is extrapolated.
+    fn position(indep: Indep, lower: Indep, upper: Indep) -> Indep;
+}
+
+/// Interpolate linearly between the grid points.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct LinearInterp;
+
+/// Take the dependent variable of the nearest grid point. Lookups halfway between two grid points
+/// take the lower grid point.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct Nearest;
+
+/// Take the dependent variable of the previous grid point (zero-order hold).
+#[derive(Default, Debug, Clone, Copy)]
+pub struct Previous;
+
+/// Take the dependent variable of the next grid point.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct Next;
+
+impl<Indep> Interpolation<Indep> for LinearInterp {
+    fn position(indep: Indep, _lower: Indep, _upper: Indep) -> Indep {
+        indep
+    }
+}
+
+impl<Indep> Interpolation<Indep> for Nearest
+where
+    Indep: Copy + PartialOrd + std::ops::Sub<Output = Indep>,
+{
+    fn position(indep: Indep, lower: Indep, upper: Indep) -> Indep {
+        if indep - lower <= upper - indep {
+            lower
+        } else {
+            upper
+        }
+    }
+}
+
+impl<Indep> Interpolation<Indep> for Previous
+where
+    Indep: PartialOrd,
+{
+    fn position(indep: Indep, lower: Indep, upper: Indep) -> Indep {
+        if indep >= upper {
+            upper
+        } else {
+            lower
+        }
+    }
+}
+
+impl<Indep> Interpolation<Indep> for Next
+where
+    Indep: PartialOrd,
+{
+    fn position(indep: Indep, lower: Indep, upper: Indep) -> Indep {
+        if indep <= lower {
+            lower
+        } else {
+            upper
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear() {
+        assert!(LinearInterp::position(1.5, 1.0, 2.0) == 1.5);
+        assert!(LinearInterp::position(-1.5, 1.0, 2.0) == -1.5);
+    }
+
+    #[test]
+    fn nearest() {
+        for (indep, expected) in [(1.2, 1.0), (1.5, 1.0), (1.7, 2.0), (0.0, 1.0), (3.0, 2.0)] {
+            assert!(Nearest::position(indep, 1.0, 2.0) == expected);
+        }
+    }
+
+    #[test]
+    fn previous() {
+        for (indep, expected) in [(1.0, 1.0), (1.9, 1.0), (2.0, 2.0), (0.0, 1.0), (3.0, 2.0)] {
+            assert!(Previous::position(indep, 1.0, 2.0) == expected);
+        }
+    }
+
+    #[test]
+    fn next() {
+        for (indep, expected) in [(1.0, 1.0), (1.1, 2.0), (2.0, 2.0), (0.0, 1.0), (3.0, 2.0)] {
+            assert!(Next::position(indep, 1.0, 2.0) == expected);
+        }
+    }
+}
@@ -3,22 +3,39 @@
 mod axis;
 mod bound;
 mod common;
+#[cfg(feature = "num-traits")]
+mod cubic1d;
+mod interpolation;
+mod nan;
 mod search;
 mod table1d;
 
 #[cfg(feature = "ndarray")]
 mod table2d;
-#[cfg(all(feature = "ndarray", feature="num-traits"))]
+#[cfg(all(feature = "ndarray", feature = "num-traits"))]
 mod table3d;
 
 pub use axis::{Axis, AxisImpl};
-pub use bound::{Bound, Clamp, Interp};
-pub use search::{Binary, CachedLinearCell, Linear, RuntimeSearch, Search};
+#[cfg(feature = "num-traits")]
+pub use bound::LeastSquares;
+pub use bound::{
+    Bound, Bounded, Clamp, Fill, Fit, Interp, InterpMargin, Periodic, Reflect, Reject,
+    RuntimeBound, RuntimeIndep,
+};
+#[cfg(feature = "num-traits")]
+pub use cubic1d::{Cubic, CubicTable1D};
+pub use interpolation::{Interpolation, LinearInterp, Nearest, Next, Previous};
+pub use nan::NanPolicy;
+pub use search::{
+    AutoSearch, Binary, BoxedSearch, BucketIndex, CachedHunt, CachedLinearAtomic, CachedLinearCell,
+    Cursor, Eytzinger, FixedDelta, InterpolationSearch, Linear, LogSpaced, PiecewiseUniform,
+    RuntimeSearch, Search, SearchRegistry, SearchStrategy, SimdLinear,
+};
 pub use table1d::LookupTable1D;
 
 #[cfg(feature = "ndarray")]
 pub use table2d::LookupTable2D;
-#[cfg(all(feature = "ndarray", feature="num-traits"))]
+#[cfg(all(feature = "ndarray", feature = "num-traits"))]
 pub use table3d::LookupTable3D;
 
 /// Possible errors occuring at table construction
@@ -35,4 +52,50 @@ pub enum Error {
     IndependentDependentLength,
     /// The independent variable provided had a length less than two
     IndependentVariableTooShort,
+    /// The independent variable was not evenly spaced, which is required by the
+    /// [FixedDelta](crate::FixedDelta) search method.
+    NonUniformSpacing,
+    /// The independent variable was not strictly positive with a constant ratio between
+    /// consecutive entries, which is required by the [LogSpaced](crate::LogSpaced) search method.
+    NonGeometricSpacing,
+    /// The independent variable spanned more than the period of a [Periodic] bound.
+    PeriodTooShort,
+    /// Only one bound of an axis was [Periodic], or the two bounds had different periods.
+    PeriodMismatch,
+    /// The margin of an [InterpMargin] bound was negative.
+    NegativeMargin,
+    /// A [LeastSquares] bound had too few points for the degree of its fit, or more points than
+    /// the independent variable.
+    FitPoints,
+}
+
+/// Possible errors occuring during a checked lookup
+///
+/// Two and three dimensional tables report values as an [AxisValue], as each axis may use a
+/// different independent variable type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LookupError<Indep = f64> {
+    /// The independent variable provided to `axis` (starting from zero) was NaN, and the table's
+    /// [NanPolicy] is [NanPolicy::Error].
+    NanInput { axis: usize },
+    /// The independent variable `value` provided to `axis` (starting from zero) was outside of the
+    /// range `lower..=upper` of the axis, on a side bounded with [Reject].
+    OutOfBounds {
+        axis: usize,
+        value: Indep,
+        lower: Indep,
+        upper: Indep,
+    },
+}
+
+/// Value of the independent variable of one axis of a multi-dimensional table, in the independent
+/// variable type of that axis. `Z` is uninhabited for two dimensional tables.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AxisValue<Indep1, Indep2, Indep3 = std::convert::Infallible> {
+    /// Value of the first axis (`x` in `f(x, y, z)`)
+    X(Indep1),
+    /// Value of the second axis (`y` in `f(x, y, z)`)
+    Y(Indep2),
+    /// Value of the third axis (`z` in `f(x, y, z)`)
+    Z(Indep3),
 }
@@ -0,0 +1,124 @@
+use crate::LookupError;
+
+/// Treatment of NaN independent variables passed to a table lookup.
+///
+/// NaN inputs are detected by the table before any searching is done, so the policy applies the
+/// same way to every [Search](crate::Search) method and every table dimension. A value is
+/// considered NaN if it cannot be compared to itself.
+///
+/// # Example
+///
+/// ```
+/// use lookup_tables::{Axis, Binary, Clamp, LookupError, LookupTable1D, NanPolicy};
+///
+/// type BinaryClampAxis = Axis<f64, Binary, Clamp, Clamp>;
+///
+/// let x = vec![0., 1., 2.];
+/// let y = vec![0., 10., 20.];
+///
+/// // NaN is propagated by default
+/// let table = LookupTable1D::<BinaryClampAxis, f64>::new(x.clone(), Binary::new(), y.clone()).unwrap();
+/// assert!(table.lookup(f64::NAN).is_nan());
+///
+/// // substitute a known value for NaN inputs
+/// let table = LookupTable1D::<BinaryClampAxis, f64>::new(x.clone(), Binary::new(), y.clone())
+///     .unwrap()
+///     .with_nan_policy(NanPolicy::Substitute(-1.));
+/// assert!(table.lookup(f64::NAN) == -1.);
+///
+/// // report NaN inputs as an error from `try_lookup`
+/// let table = LookupTable1D::<BinaryClampAxis, f64>::new(x, Binary::new(), y)
+///     .unwrap()
+///     .with_nan_policy(NanPolicy::Error);
+/// assert!(matches!(table.try_lookup(f64::NAN), Err(LookupError::NanInput { axis: 0 })));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum NanPolicy<Dep> {
+    /// Interpolate using the NaN input. For floating point dependent variables the result is NaN.
+    #[default]
+    Propagate,
+    /// Return [LookupError::NanInput] from `try_lookup`. Since `lookup` cannot return an error, it
+    /// propagates NaN inputs under this policy.
+    Error,
+    /// Return the provided dependent variable.
+    Substitute(Dep),
+}
+
+impl<Dep> NanPolicy<Dep>
+where
+    Dep: Copy,
+{
+    /// Result of a lookup with a NaN input. `propagate` interpolates using the NaN input.
+    pub(crate) fn value<F>(&self, propagate: F) -> Dep
+    where
+        F: FnOnce() -> Dep,
+    {
+        match self {
+            NanPolicy::Substitute(dep) => *dep,
+            NanPolicy::Propagate | NanPolicy::Error => propagate(),
+        }
+    }
+
+    /// Result of a checked lookup with a NaN input on `axis`. `propagate` interpolates using the
+    /// NaN input.
+    pub(crate) fn checked<Indep, F>(
+        &self,
+        axis: usize,
+        propagate: F,
+    ) -> Result<Dep, LookupError<Indep>>
+    where
+        F: FnOnce() -> Dep,
+    {
+        match self {
+            NanPolicy::Error => Err(LookupError::NanInput { axis }),
+            NanPolicy::Substitute(dep) => Ok(*dep),
+            NanPolicy::Propagate => Ok(propagate()),
+        }
+    }
+}
+
+/// Check if a value is NaN, without requiring a floating point type.
+pub(crate) fn is_nan<Indep>(value: &Indep) -> bool
+where
+    Indep: PartialOrd<Indep>,
+{
+    value.partial_cmp(value).is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_nan() {
+        assert!(is_nan(&f64::NAN));
+        assert!(is_nan(&f32::NAN));
+        assert!(!is_nan(&f64::INFINITY));
+        assert!(!is_nan(&0.0));
+        assert!(!is_nan(&3usize));
+    }
+
+    #[test]
+    fn propagate() {
+        let policy = NanPolicy::Propagate;
+        assert!(policy.value(|| f64::NAN).is_nan());
+        assert!(policy.checked::<f64, _>(0, || f64::NAN).unwrap().is_nan());
+    }
+
+    #[test]
+    fn error() {
+        let policy = NanPolicy::Error;
+        assert!(policy.value(|| f64::NAN).is_nan());
+        assert!(matches!(
+            policy.checked::<f64, _>(2, || f64::NAN),
+            Err(LookupError::NanInput { axis: 2 })
+        ));
+    }
+
+    #[test]
+    fn substitute() {
+        let policy = NanPolicy::Substitute(1.0);
+        assert!(policy.value(|| f64::NAN) == 1.0);
+        assert!(policy.checked::<f64, _>(0, || f64::NAN).unwrap() == 1.0);
+    }
+}
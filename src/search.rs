@@ -1,9 +1,11 @@
+use crate::Error;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 // todo: constructors for these instead of default
-// todo: fixed delta search method
 
 /// Linear search to find the bounding indices. Typically faster for small
 /// (<20) values in the table.
-#[derive(Default)]
+#[derive(Default, Debug, Clone, Copy)]
 pub struct Linear;
 
 impl Linear {
@@ -13,7 +15,23 @@ impl Linear {
     }
 }
 
-#[derive(Default)]
+/// Linear search accelerated with SIMD instructions, comparing 4 (`f64`) or 8 (`f32`) entries of
+/// the independent variable at a time. Bounding indices are identical to [Linear]. Typically the
+/// fastest search method for small (<20) tables of `f32` or `f64`.
+///
+/// Uses AVX on `x86_64` cpus supporting it, detected at runtime, and otherwise SSE2 (comparing 2
+/// `f64` or 4 `f32` entries per instruction). Other architectures use a scalar fallback.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct SimdLinear;
+
+impl SimdLinear {
+    /// Construct a new SIMD linear search method
+    pub fn new() -> Self {
+        SimdLinear
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy)]
 /// Binary search to find bounding indices. Useful for large datasets (>20).
 pub struct Binary;
 
@@ -43,11 +61,578 @@ impl CachedLinearCell {
     }
 }
 
+/// Compute the bounding indices directly from the value for evenly spaced independent variables.
+/// Lookups are `O(1)` regardless of table size.
+///
+/// The spacing of the independent variable is checked when the table is constructed. Construction
+/// fails with [Error::NonUniformSpacing](crate::Error::NonUniformSpacing) if any entry deviates
+/// from a uniform grid by more than the tolerance.
+///
+/// # Example
+///
+/// ```
+/// use lookup_tables::{Axis, FixedDelta, Clamp, LookupTable1D};
+///
+/// type UniformAxis = Axis<f64, FixedDelta, Clamp, Clamp>;
+///
+/// let x = ndarray::Array1::linspace(0., 10., 101).to_vec();
+/// let y = x.iter().map(|value| 3.0 * value).collect();
+///
+/// let table = LookupTable1D::<UniformAxis, f64>::new(x, FixedDelta::new(), y).unwrap();
+///
+/// assert!((table.lookup(4.25) - 12.75).abs() < 1e-10);
+///
+/// // non-uniform breakpoints are rejected at construction
+/// let x = vec![0., 1., 3.];
+/// let y = vec![0., 1., 2.];
+/// assert!(LookupTable1D::<UniformAxis, f64>::new(x, FixedDelta::new(), y).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixedDelta {
+    origin: f64,
+    inverse_delta: f64,
+    tolerance: f64,
+}
+
+impl FixedDelta {
+    /// Default tolerance on the deviation of each entry from a uniform grid, relative to the grid
+    /// spacing. Entries may additionally deviate by a few ULPs of the largest magnitude on the
+    /// axis, so grids of `f32` are accepted.
+    pub const DEFAULT_TOLERANCE: f64 = 1e-9;
+
+    /// Construct a new fixed delta search method with the default tolerance
+    pub fn new() -> Self {
+        Self::with_tolerance(Self::DEFAULT_TOLERANCE)
+    }
+
+    /// Construct a new fixed delta search method where each entry of the independent variable may
+    /// deviate from a uniform grid by `tolerance`, relative to the grid spacing.
+    pub fn with_tolerance(tolerance: f64) -> Self {
+        Self {
+            origin: 0.,
+            inverse_delta: 1.,
+            tolerance,
+        }
+    }
+}
+
+impl Default for FixedDelta {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Compute the bounding indices directly from the logarithm of the value for geometrically spaced
+/// independent variables (`x[i + 1] / x[i]` is constant). Lookups are `O(1)` regardless of table
+/// size.
+///
+/// The independent variable must be strictly positive, and its spacing is checked when the table
+/// is constructed. Construction fails with
+/// [Error::NonGeometricSpacing](crate::Error::NonGeometricSpacing) if any entry deviates from a
+/// geometric grid by more than the tolerance.
+///
+/// # Example
+///
+/// ```
+/// use lookup_tables::{Axis, LogSpaced, Clamp, LookupTable1D};
+///
+/// type FrequencyAxis = Axis<f64, LogSpaced, Clamp, Clamp>;
+///
+/// // 1 Hz to 1 MHz, one breakpoint per decade
+/// let x = vec![1., 10., 100., 1e3, 1e4, 1e5, 1e6];
+/// let y = x.iter().map(|value| 2.0 * value).collect();
+///
+/// let table = LookupTable1D::<FrequencyAxis, f64>::new(x, LogSpaced::new(), y).unwrap();
+///
+/// assert!((table.lookup(550.) - 1100.).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LogSpaced {
+    log_origin: f64,
+    inverse_log_ratio: f64,
+    tolerance: f64,
+}
+
+impl LogSpaced {
+    /// Default tolerance on the deviation of the logarithm of each entry from a uniform grid,
+    /// relative to the logarithm of the grid ratio. The logarithms may additionally deviate by a
+    /// few ULPs of the independent variable type, so grids of `f32` are accepted.
+    pub const DEFAULT_TOLERANCE: f64 = 1e-9;
+
+    /// Construct a new logarithmically spaced search method with the default tolerance
+    pub fn new() -> Self {
+        Self::with_tolerance(Self::DEFAULT_TOLERANCE)
+    }
+
+    /// Construct a new logarithmically spaced search method where the logarithm of each entry of
+    /// the independent variable may deviate from a uniform grid by `tolerance`, relative to the
+    /// logarithm of the grid ratio.
+    pub fn with_tolerance(tolerance: f64) -> Self {
+        Self {
+            log_origin: 0.,
+            inverse_log_ratio: 1.,
+            tolerance,
+        }
+    }
+}
+
+impl Default for LogSpaced {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Split the independent variable into maximal runs of evenly spaced entries when the table is
+/// constructed. Lookups binary search the (few) runs, then compute the bounding indices within the
+/// run in `O(1)`. Effective for axes that are dense around a region of interest and coarse
+/// elsewhere.
+///
+/// If the independent variable has too little uniform structure to exploit, every lookup falls
+/// back to a [Binary] search.
+///
+/// # Example
+///
+/// ```
+/// use lookup_tables::{Axis, PiecewiseUniform, Clamp, LookupTable1D};
+///
+/// type RefinedAxis = Axis<f64, PiecewiseUniform, Clamp, Clamp>;
+///
+/// // coarse from 0 to 10, fine from 10 to 11, coarse again from 11 to 20
+/// let mut x: Vec<f64> = (0..10).map(|i| i as f64).collect();
+/// x.extend((0..10).map(|i| 10. + i as f64 * 0.1));
+/// x.extend((0..=9).map(|i| 11. + i as f64));
+/// let y = x.iter().map(|value| value * value).collect();
+///
+/// let table = LookupTable1D::<RefinedAxis, f64>::new(x, PiecewiseUniform::new(), y).unwrap();
+///
+/// assert!((table.lookup(10.45) - 10.45 * 10.45).abs() < 1e-2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PiecewiseUniform {
+    segments: Vec<UniformSegment>,
+    tolerance: f64,
+}
+
+/// A run of evenly spaced entries `indep[start_idx..=end_idx]` of the independent variable
+#[derive(Debug, Clone)]
+struct UniformSegment {
+    start_idx: usize,
+    end_idx: usize,
+    origin: f64,
+    inverse_delta: f64,
+}
+
+impl PiecewiseUniform {
+    /// Default tolerance on the deviation of the spacing of each entry within a run, relative to
+    /// the spacing of the run. The spacing may additionally deviate by a few ULPs of the entries
+    /// of the run, so runs of `f32` are found.
+    pub const DEFAULT_TOLERANCE: f64 = 1e-9;
+
+    /// Minimum average number of intervals in each run for the runs to be used. Below this the
+    /// search falls back to a [Binary] search.
+    const MIN_AVERAGE_RUN_LENGTH: usize = 4;
+
+    /// Construct a new piecewise uniform search method with the default tolerance
+    pub fn new() -> Self {
+        Self::with_tolerance(Self::DEFAULT_TOLERANCE)
+    }
+
+    /// Construct a new piecewise uniform search method where the spacing between entries in a run
+    /// may deviate by `tolerance`, relative to the spacing of the run.
+    pub fn with_tolerance(tolerance: f64) -> Self {
+        Self {
+            segments: Vec::new(),
+            tolerance,
+        }
+    }
+
+    /// Number of uniform runs found in the independent variable, or `None` if there was too little
+    /// structure and lookups fall back to a [Binary] search.
+    pub fn segment_count(&self) -> Option<usize> {
+        if self.segments.is_empty() {
+            None
+        } else {
+            Some(self.segments.len())
+        }
+    }
+}
+
+impl Default for PiecewiseUniform {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Divide the range of the independent variable into evenly sized buckets when the table is
+/// constructed, each storing the index of the last entry at or below the start of the bucket.
+/// Lookups compute the bucket in `O(1)`, then linearly scan the few entries inside the bucket.
+/// Effective for large tables with irregularly spaced independent variables.
+///
+/// More buckets means fewer entries to scan per lookup at the cost of more memory. By default
+/// there is one bucket per entry in the independent variable.
+///
+/// # Example
+///
+/// ```
+/// use lookup_tables::{Axis, BucketIndex, Clamp, LookupTable1D};
+///
+/// type IrregularAxis = Axis<f64, BucketIndex, Clamp, Clamp>;
+///
+/// let x: Vec<f64> = (0..10_000).map(|i| (i as f64).powf(1.5)).collect();
+/// let y = x.iter().map(|value| 2.0 * value).collect();
+///
+/// let table = LookupTable1D::<IrregularAxis, f64>::new(x, BucketIndex::with_buckets(2_000), y).unwrap();
+///
+/// assert!((table.lookup(12345.6) - 24691.2).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BucketIndex {
+    bucket_count: Option<usize>,
+    buckets: Vec<usize>,
+    origin: f64,
+    inverse_width: f64,
+}
+
+impl BucketIndex {
+    /// Construct a new bucket index search method with one bucket per entry in the independent
+    /// variable
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Construct a new bucket index search method with `bucket_count` buckets spanning the
+    /// independent variable
+    pub fn with_buckets(bucket_count: usize) -> Self {
+        Self {
+            bucket_count: Some(std::cmp::max(1, bucket_count)),
+            ..Self::default()
+        }
+    }
+}
+
+/// Store the last set of bounding indices and hunt outwards from the last known match with a
+/// doubling stride until the value is bracketed, then binary search inside the bracket.
+///
+/// Slowly changing values are found in `O(1)` like [CachedLinearCell], while large jumps cost
+/// `O(log n)` instead of a linear scan of the table.
+#[derive(Debug, Default)]
+pub struct CachedHunt {
+    last_lower_idx: std::cell::RefCell<usize>,
+}
+
+impl CachedHunt {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_last_index(last_index: usize) -> Self {
+        Self {
+            last_lower_idx: last_index.into(),
+        }
+    }
+}
+
+/// Thread safe variant of [CachedLinearCell]. The last set of bounding indices is stored in an
+/// atomic, so tables using this search method are `Sync` and may be shared between threads.
+///
+/// Lookups from multiple threads share one cached index, so the cache is most effective when
+/// each thread looks up nearby values.
+#[derive(Debug, Default)]
+pub struct CachedLinearAtomic {
+    last_lower_idx: AtomicUsize,
+}
+
+impl CachedLinearAtomic {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_last_index(last_index: usize) -> Self {
+        Self {
+            last_lower_idx: last_index.into(),
+        }
+    }
+}
+
+/// Binary search over a copy of the independent variable stored in Eytzinger (breadth first)
+/// order, built when the table is constructed. Useful for very large datasets where
+/// [Binary] search is dominated by cache misses.
+///
+/// The first levels of the implicit search tree share a few cache lines, and the descent is
+/// branchless with the next levels prefetched, so lookups stay fast on axes with millions of
+/// entries. The copy doubles the memory used by the independent variable.
+///
+/// The copy is built by [Search::prepare] when the table is constructed. Until then, searches fall
+/// back to a [Binary] search.
+///
+/// # Example
+///
+/// ```
+/// use lookup_tables::{Axis, Eytzinger, Clamp, LookupTable1D};
+///
+/// type LargeAxis = Axis<f64, Eytzinger<f64>, Clamp, Clamp>;
+///
+/// let x: Vec<f64> = (0..10_000).map(|i| (i as f64).sqrt()).collect();
+/// let y = x.iter().map(|value| 2.0 * value).collect();
+///
+/// let table = LookupTable1D::<LargeAxis, f64>::new(x, Eytzinger::new(), y).unwrap();
+///
+/// assert!((table.lookup(12.34) - 24.68).abs() < 1e-8);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Eytzinger<Indep> {
+    /// independent variable in breadth first order. The children of `tree[k - 1]` are
+    /// `tree[2k - 1]` and `tree[2k]`
+    tree: Vec<Indep>,
+    /// `sorted_idx[k]` is the index into the independent variable of `tree[k - 1]`.
+    /// `sorted_idx[0]` is one past the last index
+    sorted_idx: Vec<usize>,
+}
+
+impl<Indep> Eytzinger<Indep> {
+    /// Construct a new Eytzinger search method
+    pub fn new() -> Self {
+        Self {
+            tree: Vec::new(),
+            sorted_idx: Vec::new(),
+        }
+    }
+}
+
+impl<Indep> Default for Eytzinger<Indep> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Recursively place `indep_values` into `tree` in breadth first order, starting from the
+/// (one based) tree node `k`
+fn eytzinger_fill<Indep>(
+    indep_values: &[Indep],
+    next_sorted_idx: &mut usize,
+    tree: &mut Vec<Indep>,
+    sorted_idx: &mut Vec<usize>,
+    k: usize,
+) where
+    Indep: Copy,
+{
+    if k <= indep_values.len() {
+        eytzinger_fill(indep_values, next_sorted_idx, tree, sorted_idx, 2 * k);
+        tree[k - 1] = indep_values[*next_sorted_idx];
+        sorted_idx[k] = *next_sorted_idx;
+        *next_sorted_idx += 1;
+        eytzinger_fill(indep_values, next_sorted_idx, tree, sorted_idx, 2 * k + 1);
+    }
+}
+
+/// Guess the bounding indices from the position of the value between the ends of the bracket
+/// being searched, refining the bracket until the value is found. Takes `O(log log n)` steps on
+/// smoothly (nearly uniformly) distributed independent variables.
+///
+/// At most `⌈log2 log2 n⌉` guesses are made before the remaining bracket is bisected instead, so
+/// adversarial independent variables take at most `⌈log2 log2 n⌉` more steps than a [Binary]
+/// search.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct InterpolationSearch;
+
+impl InterpolationSearch {
+    /// Construct a new interpolation search method
+    pub fn new() -> Self {
+        InterpolationSearch
+    }
+}
+
+/// Caller owned search state for the `lookup_with_cursor` methods of the lookup tables. The cursor
+/// stores the last set of bounding indices of each table axis, and searches linearly from them,
+/// like [CachedLinearCell].
+///
+/// Keeping the cached indices out of the table lets many independent callers (simulations,
+/// threads) share one immutable table by reference, each with their own locality hint. The
+/// table's own search method is not used by cursor lookups.
+///
+/// # Example
+///
+/// ```
+/// use lookup_tables::{Axis, Binary, Clamp, Cursor, LookupTable1D};
+///
+/// type BinaryClampAxis = Axis<f64, Binary, Clamp, Clamp>;
+///
+/// let x = ndarray::Array1::linspace(0., 10., 1000).to_vec();
+/// let y = x.iter().map(|value| 2.0 * value).collect();
+///
+/// let table = LookupTable1D::<BinaryClampAxis, f64>::new(x, Binary::new(), y).unwrap();
+///
+/// std::thread::scope(|scope| {
+///     for simulation in 0..4 {
+///         let table = &table;
+///         scope.spawn(move || {
+///             let mut cursor = Cursor::new();
+///             for step in 0..100 {
+///                 let x = simulation as f64 + step as f64 * 0.01;
+///                 assert!((table.lookup_with_cursor(&mut cursor, x) - 2.0 * x).abs() < 1e-10);
+///             }
+///         });
+///     }
+/// });
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Cursor {
+    last_lower_idx: [usize; 3],
+}
+
+impl Cursor {
+    /// Construct a new cursor starting at the lowest indices of each axis
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Search `indep_values` from the cached lower index of the axis `axis`, updating the cache
+    pub(crate) fn search<Indep>(
+        &mut self,
+        axis: usize,
+        value: Indep,
+        indep_values: &[Indep],
+    ) -> (usize, usize)
+    where
+        Indep: PartialOrd<Indep>,
+    {
+        // the same cursor may have been used on a larger table
+        let last_lower = std::cmp::min(self.last_lower_idx[axis], indep_values.len() - 2);
+
+        let index_pair = cached_linear_search(value, indep_values, last_lower);
+        self.last_lower_idx[axis] = index_pair.0;
+        index_pair
+    }
+}
+
+/// Select a search method automatically from the number and spacing of the entries in the
+/// independent variable when the table is constructed. In order of preference:
+///
+/// 1. [Linear] for small (<= 20 entries) tables
+/// 2. [FixedDelta] for evenly spaced independent variables
+/// 3. [LogSpaced] for geometrically spaced independent variables
+/// 4. [PiecewiseUniform] for independent variables made of a few evenly spaced runs
+/// 5. [Binary] otherwise
+///
+/// The selected method is reported by [AutoSearch::strategy].
+///
+/// # Example
+///
+/// ```
+/// use lookup_tables::{Axis, AutoSearch, Clamp, LookupTable1D, SearchStrategy};
+///
+/// type AutoAxis = Axis<f64, AutoSearch, Clamp, Clamp>;
+///
+/// let x = ndarray::Array1::linspace(0., 10., 101).to_vec();
+/// let y = x.iter().map(|value| 3.0 * value).collect();
+///
+/// let table = LookupTable1D::<AutoAxis, f64>::new(x, AutoSearch::new(), y).unwrap();
+///
+/// assert!(table.search().strategy() == SearchStrategy::FixedDelta);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AutoSearch {
+    selected: AutoSelected,
+}
+
+#[derive(Debug, Clone)]
+enum AutoSelected {
+    Linear(Linear),
+    Binary(Binary),
+    FixedDelta(FixedDelta),
+    LogSpaced(LogSpaced),
+    PiecewiseUniform(PiecewiseUniform),
+}
+
+/// Search method selected by [AutoSearch]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchStrategy {
+    Linear,
+    Binary,
+    FixedDelta,
+    LogSpaced,
+    PiecewiseUniform,
+}
+
+impl AutoSearch {
+    /// Largest table searched with [Linear]
+    pub const MAX_LINEAR_LENGTH: usize = 20;
+
+    /// Construct a new automatically selected search method. Until the table is constructed, a
+    /// [Binary] search is used.
+    pub fn new() -> Self {
+        Self {
+            selected: AutoSelected::Binary(Binary::new()),
+        }
+    }
+
+    /// Search method selected when the table was constructed
+    pub fn strategy(&self) -> SearchStrategy {
+        match &self.selected {
+            AutoSelected::Linear(_) => SearchStrategy::Linear,
+            AutoSelected::Binary(_) => SearchStrategy::Binary,
+            AutoSelected::FixedDelta(_) => SearchStrategy::FixedDelta,
+            AutoSelected::LogSpaced(_) => SearchStrategy::LogSpaced,
+            AutoSelected::PiecewiseUniform(_) => SearchStrategy::PiecewiseUniform,
+        }
+    }
+}
+
+impl Default for AutoSearch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Searches a sequence of increasing values by walking the independent variable alongside them,
+/// so a sorted batch of `m` lookups into a table of `n` entries costs `O(n + m)`.
+///
+/// The first value, and any value smaller than the one before it, is found with the axis search
+/// method instead.
+#[derive(Debug, Default)]
+pub(crate) struct SortedMerge {
+    low_idx: Option<usize>,
+}
+
+impl SortedMerge {
+    pub(crate) fn search<Indep, S>(
+        &mut self,
+        search: &S,
+        value: Indep,
+        indep_values: &[Indep],
+    ) -> (usize, usize)
+    where
+        Indep: PartialOrd<Indep> + Copy,
+        S: Search<Indep>,
+    {
+        let length = indep_values.len();
+
+        let mut low_idx = match self.low_idx {
+            Some(low_idx) if low_idx == 0 || indep_values[low_idx] <= value => low_idx,
+            _ => search.search(value, indep_values).0,
+        };
+
+        while low_idx + 2 < length && indep_values[low_idx + 1] <= value {
+            low_idx += 1;
+        }
+
+        self.low_idx = Some(low_idx);
+        (low_idx, low_idx + 1)
+    }
+}
+
 /// Determine search method dynamically at runtime.
+///
+/// The independent variable must convert to `f64` for the [FixedDelta] variant. Search methods
+/// outside this crate, and the remaining search methods of this crate, are chosen at runtime with
+/// a [BoxedSearch].
 pub enum RuntimeSearch {
     Linear(Linear),
     Binary(Binary),
     CachedLinearCell(CachedLinearCell),
+    CachedLinearAtomic(CachedLinearAtomic),
+    CachedHunt(CachedHunt),
+    FixedDelta(FixedDelta),
 }
 
 impl RuntimeSearch {
@@ -62,6 +647,149 @@ impl RuntimeSearch {
     pub fn cached_linear_cell() -> Self {
         RuntimeSearch::CachedLinearCell(CachedLinearCell::new())
     }
+
+    pub fn cached_linear_atomic() -> Self {
+        RuntimeSearch::CachedLinearAtomic(CachedLinearAtomic::new())
+    }
+
+    pub fn cached_hunt() -> Self {
+        RuntimeSearch::CachedHunt(CachedHunt::new())
+    }
+
+    pub fn fixed_delta() -> Self {
+        RuntimeSearch::FixedDelta(FixedDelta::new())
+    }
+}
+
+/// Any search method, boxed so that it can be chosen at runtime. Unlike [RuntimeSearch], this
+/// includes user defined search methods, at the cost of a dynamic dispatch on every search.
+///
+/// Search methods can be selected by name with a [SearchRegistry].
+pub struct BoxedSearch<Indep>
+where
+    Indep: PartialOrd<Indep>,
+{
+    search: Box<dyn Search<Indep> + Send + Sync>,
+}
+
+impl<Indep> BoxedSearch<Indep>
+where
+    Indep: PartialOrd<Indep>,
+{
+    /// Construct a new boxed search method
+    pub fn new<S>(search: S) -> Self
+    where
+        S: Search<Indep> + Send + Sync + 'static,
+    {
+        Self {
+            search: Box::new(search),
+        }
+    }
+}
+
+/// Constructs a [BoxedSearch] in a [SearchRegistry]
+type SearchConstructor<Indep> = Box<dyn Fn() -> BoxedSearch<Indep> + Send + Sync>;
+
+/// Map of names to search methods, so that search methods can be selected by name (from
+/// configuration files, for example) at runtime.
+///
+/// [SearchRegistry::new] contains the search methods of this crate, named after the snake case
+/// name of their type (`"linear"`, `"binary"`, `"cached_linear_atomic"`, ...). [BoxedSearch] is
+/// `Sync`, so [CachedLinearCell] and [CachedHunt] are not included. User defined search methods
+/// are added with [SearchRegistry::register].
+///
+/// # Example
+///
+/// ```
+/// use lookup_tables::{Axis, BoxedSearch, Clamp, LookupTable1D, Search, SearchRegistry};
+///
+/// /// Always interpolates between the first two entries
+/// struct FirstInterval;
+///
+/// impl Search<f64> for FirstInterval {
+///     fn search(&self, _value: f64, _indep_values: &[f64]) -> (usize, usize) {
+///         (0, 1)
+///     }
+/// }
+///
+/// let mut registry = SearchRegistry::new();
+/// registry.register("first_interval", || FirstInterval);
+///
+/// type BoxedAxis = Axis<f64, BoxedSearch<f64>, Clamp, Clamp>;
+///
+/// // read from a configuration file
+/// for name in ["binary", "first_interval"] {
+///     let search = registry.resolve(name).unwrap();
+///     let table = LookupTable1D::<BoxedAxis, f64>::new(vec![0., 1., 2.], search, vec![0., 1., 2.]).unwrap();
+///     assert!(table.lookup(0.5) == 0.5);
+/// }
+///
+/// assert!(registry.resolve("unknown").is_none());
+/// ```
+pub struct SearchRegistry<Indep>
+where
+    Indep: PartialOrd<Indep> + 'static,
+{
+    constructors: std::collections::HashMap<String, SearchConstructor<Indep>>,
+}
+
+impl<Indep> SearchRegistry<Indep>
+where
+    Indep: PartialOrd<Indep> + 'static,
+{
+    /// Construct a registry containing the `Sync` search methods of this crate
+    pub fn new() -> Self
+    where
+        Indep: Copy + Into<f64>,
+    {
+        let mut registry = Self::empty();
+
+        registry.register("linear", Linear::new);
+        registry.register("binary", Binary::new);
+        registry.register("cached_linear_atomic", CachedLinearAtomic::new);
+        registry.register("fixed_delta", FixedDelta::new);
+        registry.register("log_spaced", LogSpaced::new);
+        registry.register("piecewise_uniform", PiecewiseUniform::new);
+        registry.register("bucket_index", BucketIndex::new);
+        registry.register("interpolation_search", InterpolationSearch::new);
+
+        registry
+    }
+
+    /// Construct a registry without any search methods
+    pub fn empty() -> Self {
+        Self {
+            constructors: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Register a search method under `name`, replacing any search method already registered
+    /// under that name. `constructor` is called to create a new search method every time `name`
+    /// is resolved.
+    pub fn register<S, F>(&mut self, name: impl Into<String>, constructor: F)
+    where
+        S: Search<Indep> + Send + Sync + 'static,
+        F: Fn() -> S + Send + Sync + 'static,
+    {
+        self.constructors.insert(
+            name.into(),
+            Box::new(move || BoxedSearch::new(constructor())),
+        );
+    }
+
+    /// Create a new search method from the constructor registered under `name`
+    pub fn resolve(&self, name: &str) -> Option<BoxedSearch<Indep>> {
+        self.constructors.get(name).map(|constructor| constructor())
+    }
+}
+
+impl<Indep> Default for SearchRegistry<Indep>
+where
+    Indep: PartialOrd<Indep> + Copy + Into<f64> + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// Find the two bounding indices in a vector for interpolation.
@@ -72,6 +800,31 @@ where
     /// Search through a list of values, return the upper and lower indices that bound a given
     /// value
     fn search(&self, value: Indep, indep_values: &[Indep]) -> (usize, usize);
+
+    /// Inspect the independent variable once when a table is constructed. `indep_values` is
+    /// strictly monotonically increasing and has at least two entries.
+    ///
+    /// Search methods that precompute data from the independent variable, or that only support
+    /// some independent variables, override this. The default does nothing.
+    fn prepare(&mut self, indep_values: &[Indep]) -> Result<(), Error> {
+        let _ = indep_values;
+        Ok(())
+    }
+}
+
+/// Rounding error of an independent variable of magnitude `magnitude`, a few ULPs of `Indep`.
+/// Axes of `f32` are only exact to about `1e-7`, well beyond the default tolerances of the uniform
+/// spacing searches.
+fn rounding_error<Indep>(magnitude: f64) -> f64 {
+    const ULPS: f64 = 16.;
+
+    let epsilon = if std::mem::size_of::<Indep>() <= std::mem::size_of::<f32>() {
+        f32::EPSILON as f64
+    } else {
+        f64::EPSILON
+    };
+
+    ULPS * epsilon * magnitude
 }
 
 fn inbounds_pair_from_lower(low_idx: usize, indep_length: usize) -> (usize, usize) {
@@ -117,13 +870,169 @@ where
     }
 }
 
+/// Bounding indices given the index of the first entry greater than the value, as found by a linear
+/// search
+fn linear_pair(high_idx: Option<usize>, length: usize) -> (usize, usize) {
+    match high_idx {
+        Some(high_idx) => inbounds_pair_from_higher(high_idx, length),
+        None => (length - 2, length - 1),
+    }
+}
+
+impl Search<f64> for SimdLinear {
+    fn search(&self, value: f64, indep_values: &[f64]) -> (usize, usize) {
+        #[cfg(target_arch = "x86_64")]
+        if std::is_x86_feature_detected!("avx") {
+            // SAFETY: avx is available on this cpu
+            return unsafe { simd_linear_f64_avx(value, indep_values) };
+        }
+
+        simd_linear(value, indep_values, greater_mask_f64x4)
+    }
+}
+
+impl Search<f32> for SimdLinear {
+    fn search(&self, value: f32, indep_values: &[f32]) -> (usize, usize) {
+        #[cfg(target_arch = "x86_64")]
+        if std::is_x86_feature_detected!("avx") {
+            // SAFETY: avx is available on this cpu
+            return unsafe { simd_linear_f32_avx(value, indep_values) };
+        }
+
+        simd_linear(value, indep_values, greater_mask_f32x8)
+    }
+}
+
+/// Linear search over chunks of `N` entries, where bit `i` of `greater_mask(chunk, value)` is set
+/// if `chunk[i] > value`
+#[inline(always)]
+fn simd_linear<Indep, const N: usize>(
+    value: Indep,
+    indep_values: &[Indep],
+    greater_mask: impl Fn(&[Indep; N], Indep) -> u32,
+) -> (usize, usize)
+where
+    Indep: PartialOrd + Copy,
+{
+    let chunks = indep_values.chunks_exact(N);
+    let remainder = chunks.remainder();
+
+    let high_idx = chunks
+        .enumerate()
+        .find_map(|(chunk_idx, chunk)| {
+            // `chunks_exact` only yields chunks of exactly `N` entries
+            let mask = greater_mask(chunk.try_into().unwrap(), value);
+            (mask != 0).then(|| N * chunk_idx + mask.trailing_zeros() as usize)
+        })
+        .or_else(|| {
+            let offset = indep_values.len() - remainder.len();
+            remainder
+                .iter()
+                .position(|v| *v > value)
+                .map(|idx| offset + idx)
+        });
+
+    linear_pair(high_idx, indep_values.len())
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx")]
+unsafe fn simd_linear_f64_avx(value: f64, indep_values: &[f64]) -> (usize, usize) {
+    simd_linear(value, indep_values, |chunk, value| {
+        // SAFETY: only called when avx is available
+        unsafe { greater_mask_f64x4_avx(chunk, value) }
+    })
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx")]
+unsafe fn simd_linear_f32_avx(value: f32, indep_values: &[f32]) -> (usize, usize) {
+    simd_linear(value, indep_values, |chunk, value| {
+        // SAFETY: only called when avx is available
+        unsafe { greater_mask_f32x8_avx(chunk, value) }
+    })
+}
+
+/// Bit `i` of the output is set if `chunk[i] > value`
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn greater_mask_f64x4_avx(chunk: &[f64; 4], value: f64) -> u32 {
+    use std::arch::x86_64::*;
+
+    // the unaligned load reads exactly one chunk
+    let greater =
+        _mm256_cmp_pd::<_CMP_GT_OQ>(_mm256_loadu_pd(chunk.as_ptr()), _mm256_set1_pd(value));
+    _mm256_movemask_pd(greater) as u32
+}
+
+/// Bit `i` of the output is set if `chunk[i] > value`
+#[cfg(target_arch = "x86_64")]
+fn greater_mask_f64x4(chunk: &[f64; 4], value: f64) -> u32 {
+    use std::arch::x86_64::*;
+
+    // SAFETY: sse2 is always available on x86_64, and the unaligned loads read exactly one chunk
+    unsafe {
+        let value = _mm_set1_pd(value);
+        let low = _mm_cmpgt_pd(_mm_loadu_pd(chunk.as_ptr()), value);
+        let high = _mm_cmpgt_pd(_mm_loadu_pd(chunk.as_ptr().add(2)), value);
+        (_mm_movemask_pd(low) | (_mm_movemask_pd(high) << 2)) as u32
+    }
+}
+
+/// Bit `i` of the output is set if `chunk[i] > value`
+#[cfg(not(target_arch = "x86_64"))]
+fn greater_mask_f64x4(chunk: &[f64; 4], value: f64) -> u32 {
+    chunk
+        .iter()
+        .enumerate()
+        .fold(0, |mask, (idx, v)| mask | (((*v > value) as u32) << idx))
+}
+
+/// Bit `i` of the output is set if `chunk[i] > value`
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn greater_mask_f32x8_avx(chunk: &[f32; 8], value: f32) -> u32 {
+    use std::arch::x86_64::*;
+
+    // the unaligned load reads exactly one chunk
+    let greater =
+        _mm256_cmp_ps::<_CMP_GT_OQ>(_mm256_loadu_ps(chunk.as_ptr()), _mm256_set1_ps(value));
+    _mm256_movemask_ps(greater) as u32
+}
+
+/// Bit `i` of the output is set if `chunk[i] > value`
+#[cfg(target_arch = "x86_64")]
+fn greater_mask_f32x8(chunk: &[f32; 8], value: f32) -> u32 {
+    use std::arch::x86_64::*;
+
+    // SAFETY: sse2 is always available on x86_64, and the unaligned loads read exactly one chunk
+    unsafe {
+        let value = _mm_set1_ps(value);
+        let low = _mm_cmpgt_ps(_mm_loadu_ps(chunk.as_ptr()), value);
+        let high = _mm_cmpgt_ps(_mm_loadu_ps(chunk.as_ptr().add(4)), value);
+        (_mm_movemask_ps(low) | (_mm_movemask_ps(high) << 4)) as u32
+    }
+}
+
+/// Bit `i` of the output is set if `chunk[i] > value`
+#[cfg(not(target_arch = "x86_64"))]
+fn greater_mask_f32x8(chunk: &[f32; 8], value: f32) -> u32 {
+    chunk
+        .iter()
+        .enumerate()
+        .fold(0, |mask, (idx, v)| mask | (((*v > value) as u32) << idx))
+}
+
 impl<Indep> Search<Indep> for Binary
 where
     Indep: PartialOrd<Indep>,
 {
     fn search(&self, value: Indep, indep_values: &[Indep]) -> (usize, usize) {
         let length = indep_values.len();
-        let f = |v: &Indep| v.partial_cmp(&value).unwrap();
+        // NaN values compare as greater than every entry instead of panicking
+        let f = |v: &Indep| v.partial_cmp(&value).unwrap_or(std::cmp::Ordering::Less);
 
         match indep_values.binary_search_by(f) {
             Ok(matching_index) => inbounds_pair_from_lower(matching_index, length),
@@ -132,68 +1041,534 @@ where
     }
 }
 
-impl<Indep> Search<Indep> for CachedLinearCell
+/// Linearly search from a previously found lower index, returning the new index pair. The lower
+/// index of the pair is the starting point for the next search.
+fn cached_linear_search<Indep>(
+    value: Indep,
+    indep_values: &[Indep],
+    last_lower: usize,
+) -> (usize, usize)
 where
     Indep: PartialOrd<Indep>,
 {
-    fn search(&self, value: Indep, indep_values: &[Indep]) -> (usize, usize) {
-        let mut borrow_idx: std::cell::RefMut<'_, usize> = self
-            .last_lower_idx
-            .try_borrow_mut()
-            .expect("Cached RefCell was already borrowed. This should never happen");
-        let last_lower: usize = *borrow_idx;
+    let length = indep_values.len();
 
-        let length = indep_values.len();
+    if indep_values[last_lower] >= value {
+        // we need to search the lower portion of the dataset since our value is smaller than
+        // the last index
 
-        if indep_values[last_lower] >= value {
-            // we need to search the lower portion of the dataset since our value is smaller than
-            // the last index
-
-            for idx in (0..last_lower).rev() {
-                let idx_value = &indep_values[idx];
-                if idx_value < &value {
-                    // we are now at an index that is above the value, we return out
-                    let index_pair = inbounds_pair_from_lower(idx, length);
-                    *borrow_idx = index_pair.0;
-                    return index_pair;
-                }
+        for idx in (0..last_lower).rev() {
+            let idx_value = &indep_values[idx];
+            if idx_value < &value {
+                // we are now at an index that is above the value, we return out
+                return inbounds_pair_from_lower(idx, length);
             }
+        }
 
-            let index_pair = (0, 1);
-            *borrow_idx = index_pair.0;
-            return index_pair;
+        (0, 1)
+    } else {
+        for (idx, idx_value) in indep_values.iter().enumerate().skip(last_lower) {
+            if idx_value > &value {
+                // we are now at an index that is above the value, we return out
+                return inbounds_pair_from_higher(idx, length);
+            }
+        }
+
+        (length - 2, length - 1)
+    }
+}
+
+impl<Indep> Search<Indep> for CachedLinearCell
+where
+    Indep: PartialOrd<Indep>,
+{
+    fn search(&self, value: Indep, indep_values: &[Indep]) -> (usize, usize) {
+        let mut borrow_idx: std::cell::RefMut<'_, usize> = self
+            .last_lower_idx
+            .try_borrow_mut()
+            .expect("Cached RefCell was already borrowed. This should never happen");
+
+        let index_pair = cached_linear_search(value, indep_values, *borrow_idx);
+        *borrow_idx = index_pair.0;
+        index_pair
+    }
+}
+
+impl<Indep> Search<Indep> for CachedLinearAtomic
+where
+    Indep: PartialOrd<Indep>,
+{
+    fn search(&self, value: Indep, indep_values: &[Indep]) -> (usize, usize) {
+        // the cached index is only a starting point for the search, so a stale value written by
+        // another thread costs time but never correctness
+        let last_lower = self.last_lower_idx.load(Ordering::Relaxed);
+
+        let index_pair = cached_linear_search(value, indep_values, last_lower);
+        self.last_lower_idx.store(index_pair.0, Ordering::Relaxed);
+        index_pair
+    }
+}
+
+impl<Indep> Search<Indep> for CachedHunt
+where
+    Indep: PartialOrd<Indep>,
+{
+    fn search(&self, value: Indep, indep_values: &[Indep]) -> (usize, usize) {
+        let mut borrow_idx: std::cell::RefMut<'_, usize> = self
+            .last_lower_idx
+            .try_borrow_mut()
+            .expect("Cached RefCell was already borrowed. This should never happen");
+
+        let length = indep_values.len();
+        let mut low_idx = std::cmp::min(*borrow_idx, length - 2);
+        let mut high_idx;
+        let mut stride = 1;
+
+        // bracket the value so that `indep_values[low_idx] <= value < indep_values[high_idx]`,
+        // where `low_idx` may be the first index and `high_idx` may be one past the last index
+        if indep_values[low_idx] <= value {
+            // hunt up
+            high_idx = low_idx + 1;
+            while high_idx < length && indep_values[high_idx] <= value {
+                low_idx = high_idx;
+                stride *= 2;
+                high_idx = std::cmp::min(low_idx + stride, length);
+            }
         } else {
-            for idx in last_lower..length {
-                let idx_value = &indep_values[idx];
-                if idx_value > &value {
-                    // we are now at an index that is above the value, we return out
-                    let index_pair = inbounds_pair_from_higher(idx, length);
-                    *borrow_idx = index_pair.0;
-                    return index_pair;
+            // hunt down
+            high_idx = low_idx;
+            loop {
+                if high_idx < stride {
+                    low_idx = 0;
+                    break;
                 }
+
+                low_idx = high_idx - stride;
+                if indep_values[low_idx] <= value {
+                    break;
+                }
+
+                high_idx = low_idx;
+                stride *= 2;
+            }
+        }
+
+        // bisect the bracket. values below the first index leave an empty bracket at zero
+        let high_idx = std::cmp::max(high_idx, low_idx + 1);
+        let low_idx = low_idx
+            + indep_values[low_idx + 1..high_idx].partition_point(|idx_value| idx_value <= &value);
+
+        let index_pair = inbounds_pair_from_lower(low_idx, length);
+        *borrow_idx = index_pair.0;
+        index_pair
+    }
+}
+
+impl<Indep> Search<Indep> for Eytzinger<Indep>
+where
+    Indep: PartialOrd<Indep> + Copy,
+{
+    fn search(&self, value: Indep, indep_values: &[Indep]) -> (usize, usize) {
+        let length = self.tree.len();
+
+        // the tree was not built from this independent variable
+        if length != indep_values.len() {
+            return Binary.search(value, indep_values);
+        }
+
+        let mut k = 1;
+
+        while k <= length {
+            // the great-grandchildren of this node are 8 consecutive entries, fetch them while
+            // the comparisons below are evaluated
+            #[cfg(target_arch = "x86_64")]
+            // SAFETY: prefetching is a hint that never faults, even on addresses past the end of
+            // the allocation
+            unsafe {
+                use std::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+                let ptr = self.tree.as_ptr().wrapping_add(8 * k - 1);
+                _mm_prefetch::<_MM_HINT_T0>(ptr as *const i8);
             }
 
-            let index_pair = (length - 2, length - 1);
-            *borrow_idx = index_pair.0;
-            return index_pair;
+            k = 2 * k + (self.tree[k - 1] <= value) as usize;
         }
+
+        // the path taken is encoded in the bits of `k`. undo the right turns taken after the last
+        // left turn, which was taken at the first entry greater than the value
+        k >>= k.trailing_ones() + 1;
+
+        inbounds_pair_from_higher(self.sorted_idx[k], indep_values.len())
+    }
+
+    fn prepare(&mut self, indep_values: &[Indep]) -> Result<(), Error> {
+        let length = indep_values.len();
+
+        let mut tree = vec![indep_values[0]; length];
+        let mut sorted_idx = vec![length; length + 1];
+        eytzinger_fill(indep_values, &mut 0, &mut tree, &mut sorted_idx, 1);
+
+        self.tree = tree;
+        self.sorted_idx = sorted_idx;
+
+        Ok(())
+    }
+}
+
+/// `⌈log2 n⌉`, with `⌈log2 0⌉ = 0`
+fn ceil_log2(n: usize) -> usize {
+    (usize::BITS - n.saturating_sub(1).leading_zeros()) as usize
+}
+
+impl<Indep> Search<Indep> for InterpolationSearch
+where
+    Indep: PartialOrd<Indep> + Copy + Into<f64>,
+{
+    fn search(&self, value: Indep, indep_values: &[Indep]) -> (usize, usize) {
+        let length = indep_values.len();
+
+        // out of bounds (and NaN) values do not need to be searched for
+        if value.partial_cmp(&indep_values[0]) != Some(std::cmp::Ordering::Greater) {
+            return (0, 1);
+        }
+        if value >= indep_values[length - 1] {
+            return (length - 2, length - 1);
+        }
+
+        // invariant: `indep_values[low_idx] <= value < indep_values[high_idx]`
+        let mut low_idx = 0;
+        let mut high_idx = length - 1;
+        let mut guesses = ceil_log2(ceil_log2(length));
+        let value_f64: f64 = value.into();
+
+        while high_idx - low_idx > 1 {
+            let guess = if guesses > 0 {
+                guesses -= 1;
+                let low: f64 = indep_values[low_idx].into();
+                let high: f64 = indep_values[high_idx].into();
+                let fraction = (value_f64 - low) / (high - low);
+                let guess = low_idx + (fraction * (high_idx - low_idx) as f64) as usize;
+                guess.clamp(low_idx + 1, high_idx - 1)
+            } else {
+                low_idx + (high_idx - low_idx) / 2
+            };
+
+            if indep_values[guess] <= value {
+                low_idx = guess;
+            } else {
+                high_idx = guess;
+            }
+        }
+
+        inbounds_pair_from_lower(low_idx, length)
+    }
+}
+
+impl<Indep> Search<Indep> for FixedDelta
+where
+    Indep: PartialOrd<Indep> + Copy + Into<f64>,
+{
+    fn search(&self, value: Indep, indep_values: &[Indep]) -> (usize, usize) {
+        let length = indep_values.len();
+
+        // float to integer casts saturate, so values below the origin (and NaN) land on zero
+        let offset = (value.into() - self.origin) * self.inverse_delta;
+        let (mut low_idx, _) = inbounds_pair_from_lower(offset as usize, length);
+
+        // the grid is only uniform within the tolerance, so the computed index may be off by one
+        // right next to a breakpoint
+        while low_idx > 0 && indep_values[low_idx] > value {
+            low_idx -= 1;
+        }
+        while low_idx + 2 < length && indep_values[low_idx + 1] <= value {
+            low_idx += 1;
+        }
+
+        (low_idx, low_idx + 1)
+    }
+
+    fn prepare(&mut self, indep_values: &[Indep]) -> Result<(), Error> {
+        let first: f64 = indep_values[0].into();
+        let last: f64 = indep_values[indep_values.len() - 1].into();
+        let delta = (last - first) / (indep_values.len() - 1) as f64;
+        let allowed =
+            self.tolerance * delta + rounding_error::<Indep>(f64::max(first.abs(), last.abs()));
+
+        for (idx, value) in indep_values.iter().enumerate() {
+            let expected = first + idx as f64 * delta;
+            if ((*value).into() - expected).abs() > allowed {
+                return Err(Error::NonUniformSpacing);
+            }
+        }
+
+        self.origin = first;
+        self.inverse_delta = delta.recip();
+
+        Ok(())
+    }
+}
+
+impl<Indep> Search<Indep> for LogSpaced
+where
+    Indep: PartialOrd<Indep> + Copy + Into<f64>,
+{
+    fn search(&self, value: Indep, indep_values: &[Indep]) -> (usize, usize) {
+        let length = indep_values.len();
+
+        // non-positive values have a logarithm of -inf (or NaN), which saturates to zero
+        let offset = (value.into().ln() - self.log_origin) * self.inverse_log_ratio;
+        let (mut low_idx, _) = inbounds_pair_from_lower(offset as usize, length);
+
+        // the grid is only geometric within the tolerance, so the computed index may be off by
+        // one right next to a breakpoint
+        while low_idx > 0 && indep_values[low_idx] > value {
+            low_idx -= 1;
+        }
+        while low_idx + 2 < length && indep_values[low_idx + 1] <= value {
+            low_idx += 1;
+        }
+
+        (low_idx, low_idx + 1)
+    }
+
+    fn prepare(&mut self, indep_values: &[Indep]) -> Result<(), Error> {
+        let first: f64 = indep_values[0].into();
+        let last: f64 = indep_values[indep_values.len() - 1].into();
+
+        // the axis is sorted, so checking the first value is enough to ensure all are positive
+        if first <= 0. {
+            return Err(Error::NonGeometricSpacing);
+        }
+
+        let log_first = first.ln();
+        let log_ratio = (last.ln() - log_first) / (indep_values.len() - 1) as f64;
+        // the rounding of each entry is relative to its magnitude, which is an absolute error in
+        // its logarithm
+        let allowed = self.tolerance * log_ratio + rounding_error::<Indep>(1.);
+
+        for (idx, value) in indep_values.iter().enumerate() {
+            let expected = log_first + idx as f64 * log_ratio;
+            if ((*value).into().ln() - expected).abs() > allowed {
+                return Err(Error::NonGeometricSpacing);
+            }
+        }
+
+        self.log_origin = log_first;
+        self.inverse_log_ratio = log_ratio.recip();
+
+        Ok(())
+    }
+}
+
+impl<Indep> Search<Indep> for PiecewiseUniform
+where
+    Indep: PartialOrd<Indep> + Copy + Into<f64>,
+{
+    fn search(&self, value: Indep, indep_values: &[Indep]) -> (usize, usize) {
+        if self.segments.is_empty() {
+            return Binary.search(value, indep_values);
+        }
+
+        let value_f64: f64 = value.into();
+
+        // the last segment starting at or before the value. values below the first segment (and
+        // NaN) use the first segment
+        let segment_idx = self
+            .segments
+            .partition_point(|segment| segment.origin <= value_f64)
+            .saturating_sub(1);
+        let segment = &self.segments[segment_idx];
+
+        // float to integer casts saturate, so values below the origin land on the first index
+        let offset = ((value_f64 - segment.origin) * segment.inverse_delta) as usize;
+        let mut low_idx = std::cmp::min(segment.start_idx + offset, segment.end_idx - 1);
+        let length = indep_values.len();
+
+        // the runs are only uniform within the tolerance, so the computed index may be off by one
+        // right next to a breakpoint
+        while low_idx > 0 && indep_values[low_idx] > value {
+            low_idx -= 1;
+        }
+        while low_idx + 2 < length && indep_values[low_idx + 1] <= value {
+            low_idx += 1;
+        }
+
+        (low_idx, low_idx + 1)
+    }
+
+    fn prepare(&mut self, indep_values: &[Indep]) -> Result<(), Error> {
+        let values: Vec<f64> = indep_values.iter().map(|value| (*value).into()).collect();
+        let mut segments = Vec::new();
+        let mut start_idx = 0;
+
+        // greedily grow each run for as long as the spacing stays the same. consecutive runs share
+        // the breakpoint between them
+        while start_idx < values.len() - 1 {
+            let delta = values[start_idx + 1] - values[start_idx];
+            let mut end_idx = start_idx + 1;
+
+            while end_idx < values.len() - 1 {
+                let next_delta = values[end_idx + 1] - values[end_idx];
+                let magnitude = f64::max(values[start_idx].abs(), values[end_idx + 1].abs());
+                if (next_delta - delta).abs()
+                    > self.tolerance * delta + rounding_error::<Indep>(magnitude)
+                {
+                    break;
+                }
+                end_idx += 1;
+            }
+
+            segments.push(UniformSegment {
+                start_idx,
+                end_idx,
+                origin: values[start_idx],
+                inverse_delta: (end_idx - start_idx) as f64 / (values[end_idx] - values[start_idx]),
+            });
+
+            start_idx = end_idx;
+        }
+
+        let max_segments = std::cmp::max(1, (values.len() - 1) / Self::MIN_AVERAGE_RUN_LENGTH);
+
+        if segments.len() > max_segments {
+            segments.clear();
+        }
+
+        self.segments = segments;
+
+        Ok(())
+    }
+}
+
+impl<Indep> Search<Indep> for BucketIndex
+where
+    Indep: PartialOrd<Indep> + Copy + Into<f64>,
+{
+    fn search(&self, value: Indep, indep_values: &[Indep]) -> (usize, usize) {
+        let length = indep_values.len();
+
+        // float to integer casts saturate, so values below the origin (and NaN) land on the first
+        // bucket
+        let bucket = ((value.into() - self.origin) * self.inverse_width) as usize;
+        let mut low_idx = match self.buckets.get(bucket) {
+            Some(low_idx) => *low_idx,
+            None => self.buckets.last().copied().unwrap_or(0),
+        };
+
+        while low_idx > 0 && indep_values[low_idx] > value {
+            low_idx -= 1;
+        }
+        while low_idx + 2 < length && indep_values[low_idx + 1] <= value {
+            low_idx += 1;
+        }
+
+        inbounds_pair_from_lower(low_idx, length)
+    }
+
+    fn prepare(&mut self, indep_values: &[Indep]) -> Result<(), Error> {
+        let values: Vec<f64> = indep_values.iter().map(|value| (*value).into()).collect();
+        let bucket_count = self.bucket_count.unwrap_or(values.len());
+
+        let first = values[0];
+        let width = (values[values.len() - 1] - first) / bucket_count as f64;
+
+        let mut buckets = Vec::with_capacity(bucket_count);
+        let mut low_idx = 0;
+
+        for bucket in 0..bucket_count {
+            let bucket_start = first + bucket as f64 * width;
+
+            // advance to the last entry at or below the start of this bucket
+            while low_idx + 1 < values.len() && values[low_idx + 1] <= bucket_start {
+                low_idx += 1;
+            }
+
+            buckets.push(low_idx);
+        }
+
+        self.buckets = buckets;
+        self.origin = first;
+        self.inverse_width = width.recip();
+
+        Ok(())
+    }
+}
+
+impl<Indep> Search<Indep> for AutoSearch
+where
+    Indep: PartialOrd<Indep> + Copy + Into<f64>,
+{
+    fn search(&self, value: Indep, indep_values: &[Indep]) -> (usize, usize) {
+        match &self.selected {
+            AutoSelected::Linear(l) => l.search(value, indep_values),
+            AutoSelected::Binary(b) => b.search(value, indep_values),
+            AutoSelected::FixedDelta(f) => f.search(value, indep_values),
+            AutoSelected::LogSpaced(l) => l.search(value, indep_values),
+            AutoSelected::PiecewiseUniform(p) => p.search(value, indep_values),
+        }
+    }
+
+    fn prepare(&mut self, indep_values: &[Indep]) -> Result<(), Error> {
+        self.selected = if indep_values.len() <= Self::MAX_LINEAR_LENGTH {
+            AutoSelected::Linear(Linear::new())
+        } else {
+            let mut fixed_delta = FixedDelta::new();
+            let mut log_spaced = LogSpaced::new();
+            let mut piecewise = PiecewiseUniform::new();
+
+            if fixed_delta.prepare(indep_values).is_ok() {
+                AutoSelected::FixedDelta(fixed_delta)
+            } else if log_spaced.prepare(indep_values).is_ok() {
+                AutoSelected::LogSpaced(log_spaced)
+            } else if piecewise.prepare(indep_values).is_ok() && piecewise.segment_count().is_some()
+            {
+                AutoSelected::PiecewiseUniform(piecewise)
+            } else {
+                AutoSelected::Binary(Binary::new())
+            }
+        };
+
+        Ok(())
     }
 }
 
 impl<Indep> Search<Indep> for RuntimeSearch
 where
-    Indep: PartialOrd<Indep>,
+    Indep: PartialOrd<Indep> + Copy + Into<f64>,
 {
     fn search(&self, value: Indep, indep_values: &[Indep]) -> (usize, usize) {
         match &self {
             RuntimeSearch::Linear(l) => l.search(value, indep_values),
             RuntimeSearch::Binary(b) => b.search(value, indep_values),
             RuntimeSearch::CachedLinearCell(c) => c.search(value, indep_values),
+            RuntimeSearch::CachedLinearAtomic(c) => c.search(value, indep_values),
+            RuntimeSearch::CachedHunt(c) => c.search(value, indep_values),
+            RuntimeSearch::FixedDelta(f) => f.search(value, indep_values),
+        }
+    }
+
+    fn prepare(&mut self, indep_values: &[Indep]) -> Result<(), Error> {
+        match self {
+            RuntimeSearch::FixedDelta(f) => f.prepare(indep_values),
+            _ => Ok(()),
         }
     }
 }
 
+impl<Indep> Search<Indep> for BoxedSearch<Indep>
+where
+    Indep: PartialOrd<Indep>,
+{
+    fn search(&self, value: Indep, indep_values: &[Indep]) -> (usize, usize) {
+        self.search.search(value, indep_values)
+    }
+
+    fn prepare(&mut self, indep_values: &[Indep]) -> Result<(), Error> {
+        self.search.prepare(indep_values)
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::default_constructed_unit_structs)]
 mod tests {
     use super::*;
 
@@ -201,6 +1576,26 @@ mod tests {
         vec![0, 2, 4, 6, 8, 10]
     }
 
+    /// Every value should produce the same bounding indices as a binary search
+    fn assert_matches_binary<Indep, S>(
+        search: &S,
+        indep_values: &[Indep],
+        values: impl IntoIterator<Item = Indep>,
+    ) where
+        Indep: PartialOrd + Copy + std::fmt::Debug,
+        S: Search<Indep>,
+    {
+        for value in values {
+            let output = search.search(value, indep_values);
+            let expected = Binary.search(value, indep_values);
+            assert!(
+                output == expected,
+                "{} entries, {value:?}: {output:?} != {expected:?}",
+                indep_values.len()
+            );
+        }
+    }
+
     //
     // Linear Tests
     //
@@ -226,6 +1621,52 @@ mod tests {
         assert!(output.1 == 5);
     }
 
+    //
+    // SIMD Linear Tests
+    //
+
+    #[test]
+    /// every table length and value should produce the same bounding indices as a linear search,
+    /// covering tables that are and are not a multiple of the SIMD width
+    fn simd_linear_matches_linear() {
+        for length in 2..40 {
+            let x: Vec<f64> = (0..length).map(|idx| 2. * idx as f64).collect();
+            let x_f32: Vec<f32> = x.iter().map(|value| *value as f32).collect();
+
+            for idx in 0..(4 * length + 8) {
+                let value = -2.0 + idx as f64 * 0.5;
+
+                let output = SimdLinear.search(value, x.as_slice());
+                let expected = Linear.search(value, x.as_slice());
+                assert!(
+                    output == expected,
+                    "f64 {value}: {output:?} != {expected:?}"
+                );
+
+                let output = SimdLinear.search(value as f32, x_f32.as_slice());
+                assert!(
+                    output == expected,
+                    "f32 {value}: {output:?} != {expected:?}"
+                );
+
+                // the fallback used by cpus without avx
+                let output = simd_linear(value, x.as_slice(), greater_mask_f64x4);
+                assert!(
+                    output == expected,
+                    "f64 fallback {value}: {output:?} != {expected:?}"
+                );
+                let output = simd_linear(value as f32, x_f32.as_slice(), greater_mask_f32x8);
+                assert!(
+                    output == expected,
+                    "f32 fallback {value}: {output:?} != {expected:?}"
+                );
+            }
+
+            let output = SimdLinear.search(f64::NAN, x.as_slice());
+            assert!(output == Linear.search(f64::NAN, x.as_slice()));
+        }
+    }
+
     //
     // Binary Tests
     //
@@ -307,4 +1748,558 @@ mod tests {
             assert!(output.1 == 5);
         }
     }
+
+    //
+    // Cached Hunt Tests
+    //
+
+    #[test]
+    /// every starting index should match a binary search
+    fn cached_hunt_matches_binary() {
+        let x: Vec<usize> = (0..50).map(|idx| 2 * idx).collect();
+
+        for starting_index in 0..50 {
+            let cached_hunt = CachedHunt::with_last_index(starting_index);
+            let values = [51, 50, 0, 1, 200, 98, 97, 3, 3, 60, 13, 12];
+            assert_matches_binary(&cached_hunt, x.as_slice(), values);
+        }
+    }
+
+    #[test]
+    fn cached_hunt_nan() {
+        let x = [0., 1., 2., 3.];
+        let cached_hunt = CachedHunt::with_last_index(2);
+        assert!(cached_hunt.search(f64::NAN, &x) == (0, 1));
+    }
+
+    //
+    // Cached Linear Atomic Tests
+    //
+
+    #[test]
+    /// every starting index and value should produce the same bounding indices as the `RefCell`
+    /// implementation
+    fn cached_linear_atomic_matches_cell() {
+        let x = data();
+
+        for starting_index in 0..6 {
+            let cached_atomic = CachedLinearAtomic::with_last_index(starting_index);
+            let cached_cell = CachedLinearCell::with_last_index(starting_index);
+
+            for value in [5, 1, 9, 0, 10, 11, 3, 3, 7] {
+                let output = cached_atomic.search(value, x.as_slice());
+                let expected = cached_cell.search(value, x.as_slice());
+                assert!(output == expected, "{value}: {output:?} != {expected:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn cached_linear_atomic_is_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CachedLinearAtomic>();
+    }
+
+    //
+    // Cursor Tests
+    //
+
+    #[test]
+    /// each axis of a cursor is cached independently
+    fn cursor_axes() {
+        let x = data();
+        let mut cursor = Cursor::new();
+
+        assert!(cursor.search(0, 9, x.as_slice()) == (4, 5));
+        assert!(cursor.search(1, 1, x.as_slice()) == (0, 1));
+        assert!(cursor.last_lower_idx == [4, 0, 0]);
+
+        assert!(cursor.search(0, 7, x.as_slice()) == (3, 4));
+        assert!(cursor.last_lower_idx == [3, 0, 0]);
+    }
+
+    #[test]
+    /// a cursor used on a longer axis must not index out of bounds on a shorter one
+    fn cursor_shorter_axis() {
+        let mut cursor = Cursor::new();
+        let long: Vec<usize> = (0..100).collect();
+
+        assert!(cursor.search(0, 95, long.as_slice()) == (95, 96));
+        assert!(cursor.search(0, 3, data().as_slice()) == (1, 2));
+    }
+
+    //
+    // Eytzinger Tests
+    //
+
+    #[test]
+    /// every table length should match a binary search
+    fn eytzinger_matches_binary() {
+        for length in 2..40 {
+            let x: Vec<f64> = (0..length).map(|idx| 2. * idx as f64).collect();
+            let mut eytzinger = Eytzinger::new();
+            eytzinger.prepare(x.as_slice()).unwrap();
+
+            let values = (0..(4 * length + 8)).map(|idx| -2.0 + idx as f64 * 0.5);
+            assert_matches_binary(&eytzinger, x.as_slice(), values);
+
+            assert!(eytzinger.search(f64::NAN, x.as_slice()) == (0, 1));
+        }
+    }
+
+    #[test]
+    fn eytzinger_unprepared() {
+        let x = data();
+        let values = [0, 1, 5, 9, 10, 20];
+        assert_matches_binary(&Eytzinger::new(), x.as_slice(), values);
+    }
+
+    #[test]
+    fn eytzinger_layout() {
+        let mut eytzinger = Eytzinger::new();
+        eytzinger.prepare(&[0, 1, 2, 3, 4, 5, 6]).unwrap();
+
+        assert!(eytzinger.tree == [3, 1, 5, 0, 2, 4, 6]);
+        assert!(eytzinger.sorted_idx == [7, 3, 1, 5, 0, 2, 4, 6]);
+    }
+
+    //
+    // Runtime Search Tests
+    //
+
+    /// Searches linearly from the top of the table and records if it was prepared
+    struct Reversed {
+        prepared: bool,
+    }
+
+    impl Search<f64> for Reversed {
+        fn search(&self, value: f64, indep_values: &[f64]) -> (usize, usize) {
+            assert!(self.prepared);
+            let low_idx = indep_values.iter().rposition(|v| *v <= value).unwrap_or(0);
+            inbounds_pair_from_lower(low_idx, indep_values.len())
+        }
+
+        fn prepare(&mut self, _indep_values: &[f64]) -> Result<(), Error> {
+            self.prepared = true;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn runtime_search_variants() {
+        let x: Vec<u32> = vec![0, 2, 4, 6, 8, 10];
+
+        for mut search in [
+            RuntimeSearch::linear(),
+            RuntimeSearch::binary(),
+            RuntimeSearch::cached_linear_cell(),
+            RuntimeSearch::cached_linear_atomic(),
+            RuntimeSearch::cached_hunt(),
+            RuntimeSearch::fixed_delta(),
+        ] {
+            search.prepare(x.as_slice()).unwrap();
+            assert!(search.search(5, x.as_slice()) == (2, 3));
+        }
+    }
+
+    #[test]
+    fn runtime_search_fixed_delta_non_uniform() {
+        let mut search = RuntimeSearch::fixed_delta();
+        let output = search.prepare(&[0., 2., 4., 6.5, 8., 10.]);
+        assert!(matches!(output, Err(Error::NonUniformSpacing)));
+    }
+
+    #[test]
+    /// `RuntimeSearch` is not generic, and searches any independent variable
+    fn runtime_search_f32() {
+        let x: Vec<f32> = vec![0., 2., 4., 6., 8., 10.];
+        assert!(RuntimeSearch::binary().search(5f32, x.as_slice()) == (2, 3));
+    }
+
+    #[test]
+    fn boxed_search_custom() {
+        let x = uniform_data();
+        let mut search = BoxedSearch::new(Reversed { prepared: false });
+        search.prepare(x.as_slice()).unwrap();
+
+        assert!(search.search(5., x.as_slice()) == (2, 3));
+        assert!(search.search(-5., x.as_slice()) == (0, 1));
+        assert!(search.search(50., x.as_slice()) == (4, 5));
+    }
+
+    #[test]
+    fn search_registry_resolve() {
+        let mut registry = SearchRegistry::new();
+        registry.register("reversed", || Reversed { prepared: false });
+
+        let x = uniform_data();
+
+        for name in [
+            "linear",
+            "binary",
+            "fixed_delta",
+            "interpolation_search",
+            "reversed",
+        ] {
+            let mut search = registry.resolve(name).unwrap();
+            search.prepare(x.as_slice()).unwrap();
+            assert!(search.search(5., x.as_slice()) == (2, 3), "{name}");
+        }
+
+        assert!(registry.resolve("unknown").is_none());
+        assert!(SearchRegistry::<f64>::empty().resolve("linear").is_none());
+    }
+
+    //
+    // Sorted Merge Tests
+    //
+
+    #[test]
+    /// sorted values, including repeated and out of bounds values, match a binary search
+    fn sorted_merge_matches_binary() {
+        let x = data();
+        let mut merge = SortedMerge::default();
+
+        for value in [0, 0, 1, 2, 2, 3, 5, 8, 9, 10, 10, 11, 20] {
+            let output = merge.search(&Linear, value, x.as_slice());
+            let expected = Binary.search(value, x.as_slice());
+            assert!(output == expected, "{value}: {output:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    /// values out of order are searched again rather than giving incorrect indices
+    fn sorted_merge_unsorted() {
+        let x = data();
+        let mut merge = SortedMerge::default();
+
+        for value in [9, 3, 7, 1, 1, 10, 0] {
+            let output = merge.search(&Binary, value, x.as_slice());
+            let expected = Binary.search(value, x.as_slice());
+            assert!(output == expected, "{value}: {output:?} != {expected:?}");
+        }
+    }
+
+    //
+    // Fixed Delta Tests
+    //
+
+    fn uniform_data() -> Vec<f64> {
+        vec![0., 2., 4., 6., 8., 10.]
+    }
+
+    fn fixed_delta() -> FixedDelta {
+        let mut fixed_delta = FixedDelta::new();
+        fixed_delta.prepare(uniform_data().as_slice()).unwrap();
+        fixed_delta
+    }
+
+    #[test]
+    fn fixed_delta_non_uniform() {
+        let mut fixed_delta = FixedDelta::new();
+        let output = fixed_delta.prepare(&[0., 2., 4., 6.5, 8., 10.]);
+        assert!(matches!(output, Err(Error::NonUniformSpacing)));
+    }
+
+    #[test]
+    fn fixed_delta_within_tolerance() {
+        let mut fixed_delta = FixedDelta::with_tolerance(1e-3);
+        let x = [0., 2., 4.001, 6., 8., 10.];
+        fixed_delta.prepare(&x).unwrap();
+
+        // the computed index is off by one here, and must be corrected
+        let output = fixed_delta.search(4.0005, &x);
+        assert!(output == (1, 2));
+    }
+
+    #[test]
+    #[cfg(feature = "ndarray")]
+    fn fixed_delta_f32() {
+        // f32 entries deviate from the grid by far more than the default tolerance
+        let x = ndarray::Array1::<f32>::linspace(0., 10., 101).to_vec();
+        let mut fixed_delta = FixedDelta::new();
+        fixed_delta.prepare(x.as_slice()).unwrap();
+
+        assert!(fixed_delta.search(4.25f32, x.as_slice()) == (42, 43));
+        assert!(fixed_delta.search(10f32, x.as_slice()) == (99, 100));
+    }
+
+    #[test]
+    fn fixed_delta_matches_binary() {
+        let values = (0..=240).map(|idx| -1.0 + idx as f64 * 0.05);
+        assert_matches_binary(&fixed_delta(), uniform_data().as_slice(), values);
+    }
+
+    #[test]
+    fn fixed_delta_out_of_bounds() {
+        let fixed_delta = fixed_delta();
+        let x = uniform_data();
+
+        assert!(fixed_delta.search(-100., x.as_slice()) == (0, 1));
+        assert!(fixed_delta.search(100., x.as_slice()) == (4, 5));
+        assert!(fixed_delta.search(f64::NAN, x.as_slice()) == (0, 1));
+    }
+
+    //
+    // Log Spaced Tests
+    //
+
+    fn geometric_data() -> Vec<f64> {
+        vec![1., 2., 4., 8., 16., 32.]
+    }
+
+    fn log_spaced() -> LogSpaced {
+        let mut log_spaced = LogSpaced::new();
+        log_spaced.prepare(geometric_data().as_slice()).unwrap();
+        log_spaced
+    }
+
+    #[test]
+    fn log_spaced_non_geometric() {
+        let mut log_spaced = LogSpaced::new();
+        let output = log_spaced.prepare(&[1., 2., 4., 6., 16., 32.]);
+        assert!(matches!(output, Err(Error::NonGeometricSpacing)));
+    }
+
+    #[test]
+    fn log_spaced_non_positive() {
+        let mut log_spaced = LogSpaced::new();
+        let output = log_spaced.prepare(&[0., 2., 4.]);
+        assert!(matches!(output, Err(Error::NonGeometricSpacing)));
+    }
+
+    #[test]
+    fn log_spaced_f32() {
+        let x: Vec<f32> = (0..30).map(|i| 10f32.powf(i as f32 * 0.1)).collect();
+        let mut log_spaced = LogSpaced::new();
+        log_spaced.prepare(x.as_slice()).unwrap();
+
+        assert!(log_spaced.search(5f32, x.as_slice()) == (6, 7));
+        assert!(log_spaced.search(x[29], x.as_slice()) == (28, 29));
+    }
+
+    #[test]
+    fn log_spaced_matches_binary() {
+        let values = (0..=400).map(|idx| 0.5 + idx as f64 * 0.1);
+        assert_matches_binary(&log_spaced(), geometric_data().as_slice(), values);
+    }
+
+    #[test]
+    fn log_spaced_out_of_bounds() {
+        let log_spaced = log_spaced();
+        let x = geometric_data();
+
+        assert!(log_spaced.search(-100., x.as_slice()) == (0, 1));
+        assert!(log_spaced.search(0., x.as_slice()) == (0, 1));
+        assert!(log_spaced.search(1000., x.as_slice()) == (4, 5));
+        assert!(log_spaced.search(f64::NAN, x.as_slice()) == (0, 1));
+    }
+
+    //
+    // Piecewise Uniform Tests
+    //
+
+    fn piecewise_data() -> Vec<f64> {
+        // spacing of 1, then 0.25, then 2
+        vec![
+            0., 1., 2., 3., 4., 4.25, 4.5, 4.75, 5., 5.25, 5.5, 7.5, 9.5, 11.5, 13.5,
+        ]
+    }
+
+    #[test]
+    fn piecewise_uniform_segments() {
+        let mut piecewise = PiecewiseUniform::new();
+        piecewise.prepare(piecewise_data().as_slice()).unwrap();
+        assert!(piecewise.segment_count() == Some(3));
+    }
+
+    #[test]
+    fn piecewise_uniform_f32() {
+        // spacing of 1, then 0.1, then 1
+        let mut x: Vec<f32> = (0..10).map(|i| i as f32).collect();
+        x.extend((0..10).map(|i| 10. + i as f32 * 0.1));
+        x.extend((11..=20).map(|i| i as f32));
+
+        let mut piecewise = PiecewiseUniform::new();
+        piecewise.prepare(x.as_slice()).unwrap();
+        assert!(piecewise.segment_count() == Some(3));
+
+        assert!(piecewise.search(10.45f32, x.as_slice()) == (14, 15));
+    }
+
+    #[test]
+    fn piecewise_uniform_fallback() {
+        let mut piecewise = PiecewiseUniform::new();
+        let x = [0., 1., 3., 3.5, 7., 7.1, 9., 12., 12.2];
+        piecewise.prepare(&x).unwrap();
+        assert!(piecewise.segment_count().is_none());
+
+        assert!(piecewise.search(3.2, &x) == (2, 3));
+        assert!(piecewise.search(100., &x) == (7, 8));
+    }
+
+    #[test]
+    fn piecewise_uniform_matches_binary() {
+        let mut piecewise = PiecewiseUniform::new();
+        let x = piecewise_data();
+        piecewise.prepare(x.as_slice()).unwrap();
+
+        let values = (0..=320).map(|idx| -1.0 + idx as f64 * 0.05);
+        assert_matches_binary(&piecewise, x.as_slice(), values);
+
+        assert!(piecewise.search(f64::NAN, x.as_slice()) == (0, 1));
+    }
+
+    //
+    // Interpolation Search Tests
+    //
+
+    #[test]
+    fn interpolation_search_matches_binary() {
+        let uniform: Vec<f64> = (0..100).map(|idx| idx as f64 * 0.1).collect();
+        let smooth: Vec<f64> = (0..100).map(|idx| (idx as f64 * 0.1).powf(1.2)).collect();
+        // exponentially spaced entries cause poor guesses
+        let adversarial: Vec<f64> = (0..60).map(|idx| 1.5_f64.powi(idx)).collect();
+
+        for x in [uniform, smooth, adversarial, irregular_data()] {
+            let last = x[x.len() - 1];
+            let values = (0..=1000).map(|idx| -1.0 + idx as f64 * (last + 2.0) / 1000.);
+            assert_matches_binary(&InterpolationSearch, x.as_slice(), values);
+            assert_matches_binary(&InterpolationSearch, x.as_slice(), x.iter().copied());
+
+            assert!(InterpolationSearch.search(f64::NAN, x.as_slice()) == (0, 1));
+        }
+    }
+
+    thread_local! {
+        static COMPARISONS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    /// Independent variable counting the comparisons made by a search
+    #[derive(Debug, Clone, Copy)]
+    struct Counted(f64);
+
+    impl PartialEq for Counted {
+        fn eq(&self, other: &Self) -> bool {
+            COMPARISONS.set(COMPARISONS.get() + 1);
+            self.0 == other.0
+        }
+    }
+
+    impl PartialOrd for Counted {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            COMPARISONS.set(COMPARISONS.get() + 1);
+            self.0.partial_cmp(&other.0)
+        }
+    }
+
+    impl From<Counted> for f64 {
+        fn from(value: Counted) -> f64 {
+            value.0
+        }
+    }
+
+    fn comparisons<S: Search<Counted>>(search: &S, value: f64, indep_values: &[Counted]) -> usize {
+        COMPARISONS.set(0);
+        search.search(Counted(value), indep_values);
+        COMPARISONS.get()
+    }
+
+    #[test]
+    /// guesses on exponentially spaced entries barely shrink the bracket, and must not make the
+    /// search much slower than a binary search
+    fn interpolation_search_adversarial() {
+        for length in [10, 100, 1000, 10_000] {
+            // as steep as `f64` allows
+            let x: Vec<Counted> = (0..length)
+                .map(|idx| Counted((700. * idx as f64 / length as f64).exp()))
+                .collect();
+            let extra = ceil_log2(ceil_log2(length));
+
+            for idx in 1..length - 1 {
+                let value = (x[idx].0 + x[idx + 1].0) / 2.;
+                let output = comparisons(&InterpolationSearch, value, x.as_slice());
+                let binary = comparisons(&Binary, value, x.as_slice());
+
+                // `InterpolationSearch` also compares the value to both ends of the table
+                assert!(
+                    output <= binary + extra + 2,
+                    "{length} entries, {value}: {output} > {binary} + {extra} + 2"
+                );
+            }
+        }
+    }
+
+    //
+    // Auto Search Tests
+    //
+
+    fn auto_strategy(indep_values: &[f64]) -> SearchStrategy {
+        let mut auto = AutoSearch::new();
+        auto.prepare(indep_values).unwrap();
+        auto.strategy()
+    }
+
+    #[test]
+    fn auto_search_strategies() {
+        let small: Vec<f64> = (0..10).map(|idx| (idx as f64).powi(2)).collect();
+        let uniform: Vec<f64> = (0..100).map(|idx| idx as f64 * 0.1).collect();
+        let geometric: Vec<f64> = (0..100).map(|idx| 1.1_f64.powi(idx)).collect();
+        let mut piecewise: Vec<f64> = (0..50).map(|idx| idx as f64).collect();
+        piecewise.extend((0..50).map(|idx| 50. + idx as f64 * 0.1));
+        let irregular: Vec<f64> = (0..100).map(|idx| (idx as f64).powf(1.3)).collect();
+
+        assert!(auto_strategy(&small) == SearchStrategy::Linear);
+        assert!(auto_strategy(&uniform) == SearchStrategy::FixedDelta);
+        assert!(auto_strategy(&geometric) == SearchStrategy::LogSpaced);
+        assert!(auto_strategy(&piecewise) == SearchStrategy::PiecewiseUniform);
+        assert!(auto_strategy(&irregular) == SearchStrategy::Binary);
+    }
+
+    #[test]
+    #[cfg(feature = "ndarray")]
+    fn auto_search_f32() {
+        let uniform = ndarray::Array1::<f32>::linspace(0., 10., 101).to_vec();
+        let mut auto = AutoSearch::new();
+        auto.prepare(uniform.as_slice()).unwrap();
+        assert!(auto.strategy() == SearchStrategy::FixedDelta);
+
+        let geometric: Vec<f32> = (0..30).map(|i| 10f32.powf(i as f32 * 0.1)).collect();
+        let mut auto = AutoSearch::new();
+        auto.prepare(geometric.as_slice()).unwrap();
+        assert!(auto.strategy() == SearchStrategy::LogSpaced);
+    }
+
+    //
+    // Bucket Index Tests
+    //
+
+    fn irregular_data() -> Vec<f64> {
+        vec![0., 0.1, 0.15, 3., 3.5, 3.6, 3.7, 8., 12., 12.01, 20.]
+    }
+
+    #[test]
+    /// any number of buckets should match a binary search
+    fn bucket_index_matches_binary() {
+        let x = irregular_data();
+
+        for bucket_count in [1, 2, 3, 7, 11, 50, 1000] {
+            let mut bucket_index = BucketIndex::with_buckets(bucket_count);
+            bucket_index.prepare(x.as_slice()).unwrap();
+
+            let values = (0..=480).map(|idx| -2.0 + idx as f64 * 0.05);
+            assert_matches_binary(&bucket_index, x.as_slice(), values);
+        }
+    }
+
+    #[test]
+    fn bucket_index_out_of_bounds() {
+        let mut bucket_index = BucketIndex::new();
+        let x = irregular_data();
+        bucket_index.prepare(x.as_slice()).unwrap();
+
+        assert!(bucket_index.search(-100., x.as_slice()) == (0, 1));
+        assert!(bucket_index.search(100., x.as_slice()) == (9, 10));
+        assert!(bucket_index.search(f64::NAN, x.as_slice()) == (0, 1));
+    }
 }
@@ -1,8 +1,10 @@
 use crate::axis;
 use crate::bound;
 use crate::common;
+use crate::interpolation;
+use crate::nan;
 use crate::search;
-use crate::Error;
+use crate::{Error, LookupError};
 use std::ops::{Add, Div, Mul, Sub};
 
 /// One dimensional lookup table - approximate `f(x)` given `x`
@@ -53,14 +55,22 @@ pub struct LookupTable1D<Axis: axis::AxisImpl, Dep> {
     indep: Vec<<Axis as axis::AxisImpl>::Indep>,
     dep: Vec<Dep>,
     search: <Axis as axis::AxisImpl>::Search,
+    lower_bound: <Axis as axis::AxisImpl>::LowerBound,
+    upper_bound: <Axis as axis::AxisImpl>::UpperBound,
+    nan_policy: nan::NanPolicy<Dep>,
 }
 
-impl<Indep, Search, LowerBound, UpperBound, Dep>
-    LookupTable1D<axis::Axis<Indep, Search, LowerBound, UpperBound>, Dep>
+impl<Indep, Search, LowerBound, UpperBound, Interpolation, Dep>
+    LookupTable1D<axis::Axis<Indep, Search, LowerBound, UpperBound, Interpolation>, Dep>
 where
     Indep: std::cmp::PartialOrd,
+    Search: search::Search<Indep>,
+    LowerBound: bound::Bound<Indep, Dep>,
+    UpperBound: bound::Bound<Indep, Dep>,
+    Dep: Clone,
 {
-    /// Construct a new lookup table
+    /// Construct a new lookup table with the default bounds of the axis. See
+    /// [with_bounds](Self::with_bounds) to provide the bounds.
     ///
     /// # Args
     ///
@@ -93,7 +103,50 @@ where
     /// // lookup table with linear searching, using `x` and `y = f(x)`
     /// let table = LookupTable1D::<LinearInterpAxis, f64>::new(x, Linear::new(), y);
     /// ```
-    pub fn new(mut indep: Vec<Indep>, search: Search, mut dep: Vec<Dep>) -> Result<Self, Error> {
+    pub fn new(indep: Vec<Indep>, search: Search, dep: Vec<Dep>) -> Result<Self, Error>
+    where
+        LowerBound: Default,
+        UpperBound: Default,
+    {
+        Self::with_bounds(
+            indep,
+            search,
+            LowerBound::default(),
+            UpperBound::default(),
+            dep,
+        )
+    }
+
+    /// Construct a new lookup table with the provided bounds
+    ///
+    /// # Args
+    ///
+    /// ## `indep`
+    ///
+    /// List of independent variables (`x` in `f(x)`).
+    ///
+    /// ## `search`
+    ///
+    /// Search method for `indep`. Implements the [Search](crate::search::Search) trait.
+    ///
+    /// ## `lower_bound`
+    ///
+    /// Behavior below the lower bound of `indep`. Implements the [Bound](crate::Bound) trait.
+    ///
+    /// ## `upper_bound`
+    ///
+    /// Behavior above the upper bound of `indep`. Implements the [Bound](crate::Bound) trait.
+    ///
+    /// ## `dep`
+    ///
+    /// List of dependent variables (`f(x)`).
+    pub fn with_bounds(
+        mut indep: Vec<Indep>,
+        mut search: Search,
+        mut lower_bound: LowerBound,
+        mut upper_bound: UpperBound,
+        mut dep: Vec<Dep>,
+    ) -> Result<Self, Error> {
         match common::check_independent_variable(indep.as_slice())? {
             common::IndependentVariableOrdering::MonotonicallyIncreasing => {}
             common::IndependentVariableOrdering::MonotonicallyDecreasing => {
@@ -104,12 +157,40 @@ where
 
         common::check_lengths(indep.len(), dep.len())?;
 
-        Ok(Self { indep, search, dep })
+        if let Some(seam) = bound::periodic_seam(&lower_bound, &upper_bound, indep.as_slice())? {
+            indep.push(seam);
+            dep.push(dep[0].clone());
+        }
+
+        search.prepare(indep.as_slice())?;
+        lower_bound.prepare(indep.as_slice())?;
+        upper_bound.prepare(indep.as_slice())?;
+
+        Ok(Self {
+            indep,
+            search,
+            dep,
+            lower_bound,
+            upper_bound,
+            nan_policy: nan::NanPolicy::default(),
+        })
+    }
+
+    /// Set the treatment of NaN inputs to lookups. Defaults to
+    /// [NanPolicy::Propagate](crate::NanPolicy::Propagate).
+    pub fn with_nan_policy(mut self, nan_policy: nan::NanPolicy<Dep>) -> Self {
+        self.nan_policy = nan_policy;
+        self
+    }
+
+    /// Search method of the independent variable
+    pub fn search(&self) -> &Search {
+        &self.search
     }
 }
 
-impl<Indep, Search, LowerBound, UpperBound, Dep>
-    LookupTable1D<axis::Axis<Indep, Search, LowerBound, UpperBound>, Dep>
+impl<Indep, Search, LowerBound, UpperBound, Interpolation, Dep>
+    LookupTable1D<axis::Axis<Indep, Search, LowerBound, UpperBound, Interpolation>, Dep>
 where
     Search: search::Search<Indep>,
     // TODO: HoldHigh / HoldLow does not require so many strict bounds
@@ -120,39 +201,169 @@ where
         + Add<Dep, Output = Dep>,
     Indep: Copy
         + Sub<Indep, Output = Indep>
+        + Add<Indep, Output = Indep>
+        + Mul<Indep, Output = Indep>
         + std::cmp::PartialOrd
         //
         + std::fmt::Debug,
-    LowerBound: bound::Bound<Indep>,
-    UpperBound: bound::Bound<Indep>,
+    LowerBound: bound::Bound<Indep, Dep>,
+    UpperBound: bound::Bound<Indep, Dep>,
+    Interpolation: interpolation::Interpolation<Indep>,
 {
     pub fn lookup(&self, x: Indep) -> Dep {
-        let (idx_l, idx_h) = self.search.search(x, self.indep.as_slice());
+        if nan::is_nan(&x) {
+            return self.nan_policy.value(|| self.interpolate(x, (0, 1)));
+        }
+
+        let (bounded_x, negate_x) = self.bound(x);
+        let x = match bound::stencil(bounded_x, false, |x| {
+            self.search.search(x, self.indep.as_slice())
+        }) {
+            Ok(x) => x,
+            Err(early) => return early.unchecked(),
+        };
+
+        bound::negate(&[negate_x], self.evaluate(x))
+    }
+
+    /// Lookup, returning an error for values beyond a [Reject](crate::Reject) bound, or for NaN
+    /// inputs when the table's [NanPolicy](crate::NanPolicy) is
+    /// [NanPolicy::Error](crate::NanPolicy::Error).
+    pub fn try_lookup(&self, x: Indep) -> Result<Dep, LookupError<Indep>> {
+        if nan::is_nan(&x) {
+            return self.nan_policy.checked(0, || self.interpolate(x, (0, 1)));
+        }
+
+        let (bounded_x, negate_x) = self.bound(x);
+        let x = match bound::stencil(bounded_x, true, |x| {
+            self.search.search(x, self.indep.as_slice())
+        }) {
+            Ok(x) => x,
+            Err(early) => {
+                return early.checked(|| bound::out_of_bounds(0, x, self.indep.as_slice(), |x| x))
+            }
+        };
+
+        Ok(bound::negate(&[negate_x], self.evaluate(x)))
+    }
+
+    /// Lookup using the caller owned `cursor` to search the independent variable, instead of the
+    /// table's search method. See [Cursor](crate::Cursor) for more information.
+    pub fn lookup_with_cursor(&self, cursor: &mut search::Cursor, x: Indep) -> Dep {
+        if nan::is_nan(&x) {
+            return self.nan_policy.value(|| self.interpolate(x, (0, 1)));
+        }
+
+        let (bounded_x, negate_x) = self.bound(x);
+        let x = match bound::stencil(bounded_x, false, |x| {
+            cursor.search(0, x, self.indep.as_slice())
+        }) {
+            Ok(x) => x,
+            Err(early) => return early.unchecked(),
+        };
+
+        bound::negate(&[negate_x], self.evaluate(x))
+    }
+
+    /// Lookup every value in `xs`, writing the results to the same index of `out`.
+    ///
+    /// `xs` should be sorted in increasing order, in which case the table is searched by walking
+    /// the independent variable alongside `xs` in a single pass. Values smaller than the one
+    /// before them are found with the table's search method instead.
+    ///
+    /// # Panics
+    ///
+    /// If `xs` and `out` have different lengths
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lookup_tables::{Axis, Binary, Clamp, LookupTable1D};
+    ///
+    /// type BinaryClampAxis = Axis<f64, Binary, Clamp, Clamp>;
+    ///
+    /// let x = vec![0., 1., 2., 3.];
+    /// let y = vec![0., 10., 20., 30.];
+    /// let table = LookupTable1D::<BinaryClampAxis, f64>::new(x, Binary::new(), y).unwrap();
+    ///
+    /// let sweep = [-1.0, 0.5, 1.5, 2.5, 10.];
+    /// let mut output = [0.; 5];
+    /// table.lookup_sorted_batch(&sweep, &mut output);
+    ///
+    /// assert!(output == [0., 5., 15., 25., 30.]);
+    /// ```
+    pub fn lookup_sorted_batch(&self, xs: &[Indep], out: &mut [Dep]) {
+        assert_eq!(
+            xs.len(),
+            out.len(),
+            "input and output of a batch lookup must have the same length"
+        );
+
+        let mut merge = search::SortedMerge::default();
+
+        for (x, out) in xs.iter().zip(out.iter_mut()) {
+            if nan::is_nan(x) {
+                *out = self.nan_policy.value(|| self.interpolate(*x, (0, 1)));
+                continue;
+            }
+
+            let (bounded_x, negate_x) = self.bound(*x);
+            let x = match bound::stencil(bounded_x, false, |x| {
+                merge.search(&self.search, x, self.indep.as_slice())
+            }) {
+                Ok(x) => x,
+                Err(early) => {
+                    *out = early.unchecked();
+                    continue;
+                }
+            };
+
+            *out = bound::negate(&[negate_x], self.evaluate(x));
+        }
+    }
+
+    fn bound(&self, x: Indep) -> (bound::Bounded<Indep, Dep>, bound::Negation<Dep>) {
+        bound::apply(
+            &self.lower_bound,
+            &self.upper_bound,
+            x,
+            self.indep.as_slice(),
+        )
+    }
+
+    fn evaluate(&self, x: bound::Stencil<Indep>) -> Dep {
+        match x.position::<Interpolation>(self.indep.as_slice()) {
+            bound::Stencil::Interpolate(x, index_pair) => self.interpolate(x, index_pair),
+            bound::Stencil::Fit(fit) => bound::sum(
+                fit.weights(self.indep.as_slice())
+                    .map(|(index, weight)| self.dep[index] * weight),
+            ),
+        }
+    }
 
+    fn interpolate(&self, x: Indep, (idx_l, idx_h): (usize, usize)) -> Dep {
         let x_l: Indep = self.indep[idx_l];
         let x_h: Indep = self.indep[idx_h];
 
         let y_l: Dep = self.dep[idx_l];
         let y_h: Dep = self.dep[idx_h];
 
-        // bound x acording to the axis we are interpolating on
-        // unwrap is safe here as we have checked its at least length 2
-        let x = LowerBound::lower_bound(x, *self.indep.first().unwrap());
-        let x = UpperBound::upper_bound(x, *self.indep.last().unwrap());
-
         let slope = (y_h - y_l) / (x_h - x_l);
 
-        return slope * (x - x_l) + y_l;
+        slope * (x - x_l) + y_l
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::default_constructed_unit_structs)]
 mod tests {
     use super::*;
     const TOL: f64 = 1e-10;
 
     type AxisInterp = axis::Axis<f64, search::Linear, bound::Interp, bound::Interp>;
     type Table1DLinearInterp = LookupTable1D<AxisInterp, f64>;
+    type Table1DFixedDeltaInterp =
+        LookupTable1D<axis::Axis<f64, search::FixedDelta, bound::Interp, bound::Interp>, f64>;
 
     //
     // Table Construction
@@ -192,6 +403,13 @@ mod tests {
         LookupTable1D::new(x, search, y).unwrap()
     }
 
+    fn fixed_delta_simple_table() -> Table1DFixedDeltaInterp {
+        let x = vec![3., 2., 1., 0.];
+        let y = vec![3., 2., 1., 0.];
+        let search = search::FixedDelta::new();
+        LookupTable1D::new(x, search, y).unwrap()
+    }
+
     //
     // Table Construction Tests
     //
@@ -226,6 +444,15 @@ mod tests {
         assert!(output.is_err());
     }
 
+    #[test]
+    fn construct_table_non_uniform_fixed_delta() {
+        let x = vec![0., 1., 2.5, 3.];
+        let y = vec![0., 1., 2., 3.];
+        let output: Result<Table1DFixedDeltaInterp, _> =
+            LookupTable1D::new(x, search::FixedDelta::new(), y);
+        assert!(matches!(output, Err(Error::NonUniformSpacing)));
+    }
+
     #[test]
     fn construct_table_mismatched_lengths() {
         let x = vec![3., 2., 1.];
@@ -379,6 +606,501 @@ mod tests {
         }
     }
 
+    //
+    // FixedDelta Tests
+    //
+
+    #[test]
+    fn fixed_delta_1() {
+        let table = fixed_delta_simple_table();
+        let output = table.lookup(0.5);
+        float_eq::assert_float_eq!(output, 0.5, abs <= TOL);
+    }
+
+    #[test]
+    fn fixed_delta_2() {
+        let table = fixed_delta_simple_table();
+        let output = table.lookup(2.2);
+        float_eq::assert_float_eq!(output, 2.2, abs <= TOL);
+    }
+
+    #[test]
+    fn fixed_delta_lower_oob() {
+        let table = fixed_delta_simple_table();
+        let output = table.lookup(-1.0);
+
+        float_eq::assert_float_eq!(output, -1.0, abs <= TOL);
+    }
+
+    #[test]
+    fn fixed_delta_higher_oob() {
+        let table = fixed_delta_simple_table();
+        let output = table.lookup(100.0);
+
+        float_eq::assert_float_eq!(output, 100.0, abs <= TOL);
+    }
+
+    //
+    // LogSpaced Tests
+    //
+
+    #[test]
+    fn log_spaced_1() {
+        let x = vec![0.1, 1., 10., 100., 1000.];
+        let y = x.iter().map(|value| value * 2.).collect();
+        let table: LookupTable1D<
+            axis::Axis<f64, search::LogSpaced, bound::Clamp, bound::Clamp>,
+            f64,
+        > = LookupTable1D::new(x, search::LogSpaced::new(), y).unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(5.), 10., abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(250.), 500., abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(0.), 0.2, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(1e6), 2000., abs <= TOL);
+    }
+
+    //
+    // RuntimeSearch Tests
+    //
+
+    #[test]
+    fn runtime_search_f32() {
+        let x: Vec<f32> = vec![0., 1., 2., 3.];
+        let y = vec![0., 10., 20., 30.];
+        let table: LookupTable1D<
+            axis::Axis<f32, search::RuntimeSearch, bound::Clamp, bound::Clamp>,
+            f32,
+        > = LookupTable1D::new(x, search::RuntimeSearch::binary(), y).unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(1.5), 15., abs <= 1e-5);
+    }
+
+    //
+    // Cursor Tests
+    //
+
+    #[test]
+    fn cursor_matches_lookup() {
+        let table = binary_simple_table();
+        let mut cursor = search::Cursor::new();
+
+        for x in [0.5, 2.2, -1.0, 100.0, 1.0, 0.0, 3.0, 2.9] {
+            let output = table.lookup_with_cursor(&mut cursor, x);
+            float_eq::assert_float_eq!(output, table.lookup(x), abs <= TOL);
+        }
+    }
+
+    //
+    // Sorted Batch Tests
+    //
+
+    #[test]
+    fn sorted_batch_matches_lookup() {
+        let table = binary_simple_table();
+        let xs = [-1.0, -0.5, 0.0, 0.5, 1.0, 1.0, 2.2, 2.9, 3.0, 100.0];
+        let mut out = [0.; 10];
+
+        table.lookup_sorted_batch(&xs, &mut out);
+
+        for (x, output) in xs.iter().zip(out.iter()) {
+            float_eq::assert_float_eq!(*output, table.lookup(*x), abs <= TOL);
+        }
+    }
+
+    #[test]
+    fn sorted_batch_unsorted() {
+        let table = linear_simple_table();
+        let xs = [2.5, 0.5, 1.5, -1.0];
+        let mut out = [0.; 4];
+
+        table.lookup_sorted_batch(&xs, &mut out);
+
+        for (x, output) in xs.iter().zip(out.iter()) {
+            float_eq::assert_float_eq!(*output, table.lookup(*x), abs <= TOL);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn sorted_batch_mismatched_lengths() {
+        let table = linear_simple_table();
+        let mut out = [0.; 2];
+        table.lookup_sorted_batch(&[0., 1., 2.], &mut out);
+    }
+
+    //
+    // NaN Policy Tests
+    //
+
+    #[test]
+    fn nan_propagate() {
+        let table = linear_clamp_table();
+        assert!(table.lookup(f64::NAN).is_nan());
+        assert!(table.try_lookup(f64::NAN).unwrap().is_nan());
+    }
+
+    #[test]
+    fn nan_every_search() {
+        // searches which would otherwise panic or return arbitrary intervals
+        let table = binary_simple_table().with_nan_policy(nan::NanPolicy::Substitute(-1.));
+        assert!(table.lookup(f64::NAN) == -1.);
+
+        let table = fixed_delta_simple_table().with_nan_policy(nan::NanPolicy::Substitute(-1.));
+        assert!(table.lookup(f64::NAN) == -1.);
+
+        let table = cached_linear_cell_simple_table(2);
+        assert!(table.lookup(f64::NAN).is_nan());
+        float_eq::assert_float_eq!(table.lookup(2.5), 2.5, abs <= TOL);
+    }
+
+    #[test]
+    fn nan_error() {
+        let table = linear_simple_table().with_nan_policy(nan::NanPolicy::Error);
+        assert!(matches!(
+            table.try_lookup(f64::NAN),
+            Err(LookupError::NanInput { axis: 0 })
+        ));
+        float_eq::assert_float_eq!(table.try_lookup(1.5).unwrap(), 1.5, abs <= TOL);
+        // lookup cannot return an error and propagates instead
+        assert!(table.lookup(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn nan_substitute_batch_and_cursor() {
+        let table = linear_simple_table().with_nan_policy(nan::NanPolicy::Substitute(100.));
+        let mut cursor = search::Cursor::new();
+        assert!(table.lookup_with_cursor(&mut cursor, f64::NAN) == 100.);
+
+        let xs = [0.5, f64::NAN, 1.5];
+        let mut out = [0.; 3];
+        table.lookup_sorted_batch(&xs, &mut out);
+        float_eq::assert_float_eq!(out, [0.5, 100., 1.5], abs_all <= TOL);
+    }
+
+    //
+    // Bound Instance Tests
+    //
+
+    /// Interpolate up to `margin` beyond the bound
+    struct Margin {
+        margin: f64,
+    }
+
+    impl bound::Bound<f64, f64> for Margin {
+        fn upper_bound(&self, indep: f64, upper_bound: f64) -> bound::Bounded<f64, f64> {
+            bound::Bounded::Indep(indep.min(upper_bound + self.margin))
+        }
+
+        fn lower_bound(&self, indep: f64, lower_bound: f64) -> bound::Bounded<f64, f64> {
+            bound::Bounded::Indep(indep.max(lower_bound - self.margin))
+        }
+    }
+
+    #[test]
+    fn with_bounds_instances() {
+        let x = vec![0., 1., 2., 3.];
+        let y = vec![0., 1., 2., 3.];
+        let table: LookupTable1D<axis::Axis<f64, search::Binary, Margin, Margin>, f64> =
+            LookupTable1D::with_bounds(
+                x,
+                search::Binary,
+                Margin { margin: 0.5 },
+                Margin { margin: 2.0 },
+                y,
+            )
+            .unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(1.5), 1.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(-0.25), -0.25, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(-10.), -0.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(4.), 4., abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(10.), 5., abs <= TOL);
+    }
+
+    //
+    // Fill Tests
+    //
+
+    #[test]
+    fn fill_sides() {
+        let x = vec![0., 1., 2., 3.];
+        let y = vec![0., 1., 2., 3.];
+        let table: LookupTable1D<
+            axis::Axis<f64, search::CachedLinearCell, bound::Fill<f64>, bound::Interp>,
+            f64,
+        > = LookupTable1D::with_bounds(
+            x,
+            search::CachedLinearCell::new(),
+            bound::Fill::new(-10.),
+            bound::Interp,
+            y,
+        )
+        .unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(1.5), 1.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(0.), 0., abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(-0.1), -10., abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(4.), 4., abs <= TOL);
+        assert!(table.try_lookup(-0.1) == Ok(-10.));
+
+        let xs = [-2., -1., 0.5, 3.5];
+        let mut out = [0.; 4];
+        table.lookup_sorted_batch(&xs, &mut out);
+        float_eq::assert_float_eq!(out, [-10., -10., 0.5, 3.5], abs_all <= TOL);
+    }
+
+    #[test]
+    fn fill_reflected_antisymmetric() {
+        let x = vec![0., 5., 10.];
+        let y = vec![0., 1., 2.];
+        let table: LookupTable1D<
+            axis::Axis<f64, search::Binary, bound::Reflect<f64>, bound::Fill<f64>>,
+            f64,
+        > = LookupTable1D::with_bounds(
+            x,
+            search::Binary,
+            bound::Reflect::new().antisymmetric(),
+            bound::Fill::new(7.),
+            y,
+        )
+        .unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(-5.), -1., abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(20.), 7., abs <= TOL);
+        // reflected beyond the filled upper bound
+        float_eq::assert_float_eq!(table.lookup(-20.), -7., abs <= TOL);
+        assert!(table.try_lookup(-20.) == Ok(-7.));
+    }
+
+    //
+    // Periodic Tests
+    //
+
+    type PeriodicAxis = axis::Axis<f64, search::Linear, bound::Periodic<f64>, bound::Periodic<f64>>;
+
+    fn periodic_table(x: Vec<f64>, y: Vec<f64>) -> Result<LookupTable1D<PeriodicAxis, f64>, Error> {
+        LookupTable1D::with_bounds(
+            x,
+            search::Linear,
+            bound::Periodic::new(360.),
+            bound::Periodic::new(360.),
+            y,
+        )
+    }
+
+    #[test]
+    fn periodic_seam() {
+        let table = periodic_table(vec![0., 90., 180., 270.], vec![0., 1., 2., 3.]).unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(45.), 0.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(315.), 1.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(360.), 0., abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(-90.), 3., abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(-45.), 1.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(3. * 360. + 135.), 1.5, abs <= TOL);
+    }
+
+    #[test]
+    fn periodic_padded() {
+        // the duplicated endpoint is already present
+        let table =
+            periodic_table(vec![0., 90., 180., 270., 360.], vec![0., 1., 2., 3., 0.]).unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(315.), 1.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(-45.), 1.5, abs <= TOL);
+    }
+
+    #[test]
+    fn periodic_decreasing() {
+        let table = periodic_table(vec![270., 180., 90., 0.], vec![3., 2., 1., 0.]).unwrap();
+        float_eq::assert_float_eq!(table.lookup(315.), 1.5, abs <= TOL);
+    }
+
+    #[test]
+    fn periodic_too_short() {
+        let output = periodic_table(vec![0., 180., 400.], vec![0., 1., 2.]);
+        assert!(matches!(output, Err(Error::PeriodTooShort)));
+    }
+
+    #[test]
+    fn periodic_one_sided() {
+        type OneSidedAxis = axis::Axis<f64, search::Binary, bound::Clamp, bound::Periodic<f64>>;
+
+        let output: Result<LookupTable1D<OneSidedAxis, f64>, _> = LookupTable1D::with_bounds(
+            vec![0., 90., 180., 270.],
+            search::Binary,
+            bound::Clamp,
+            bound::Periodic::new(360.),
+            vec![0., 10., 20., 30.],
+        );
+        assert!(matches!(output, Err(Error::PeriodMismatch)));
+
+        let output: Result<LookupTable1D<PeriodicAxis, f64>, _> = LookupTable1D::with_bounds(
+            vec![0., 90., 180., 270.],
+            search::Linear,
+            bound::Periodic::new(360.),
+            bound::Periodic::new(400.),
+            vec![0., 10., 20., 30.],
+        );
+        assert!(matches!(output, Err(Error::PeriodMismatch)));
+    }
+
+    //
+    // Reject Tests
+    //
+
+    #[test]
+    fn reject_try_lookup() {
+        let x = vec![0., 1., 2., 3.];
+        let y = vec![0., 1., 2., 3.];
+        let table: LookupTable1D<
+            axis::Axis<f64, search::Binary, bound::Reject, bound::Reject>,
+            f64,
+        > = LookupTable1D::new(x, search::Binary, y).unwrap();
+
+        float_eq::assert_float_eq!(table.try_lookup(1.5).unwrap(), 1.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.try_lookup(0.).unwrap(), 0., abs <= TOL);
+        float_eq::assert_float_eq!(table.try_lookup(3.).unwrap(), 3., abs <= TOL);
+
+        assert!(
+            table.try_lookup(-0.5)
+                == Err(LookupError::OutOfBounds {
+                    axis: 0,
+                    value: -0.5,
+                    lower: 0.,
+                    upper: 3.
+                })
+        );
+        assert!(matches!(
+            table.try_lookup(3.5),
+            Err(LookupError::OutOfBounds { axis: 0, .. })
+        ));
+
+        // unchecked lookups clamp
+        float_eq::assert_float_eq!(table.lookup(3.5), 3., abs <= TOL);
+    }
+
+    //
+    // Interpolation Tests
+    //
+
+    #[test]
+    fn interpolation_kinds() {
+        use crate::{Nearest, Next, Previous};
+
+        let x = vec![0., 1., 2., 3.];
+        let y = vec![0., 10., 20., 30.];
+
+        let previous: LookupTable1D<
+            axis::Axis<f64, search::Binary, bound::Clamp, bound::Clamp, Previous>,
+            f64,
+        > = LookupTable1D::new(x.clone(), search::Binary, y.clone()).unwrap();
+        let next: LookupTable1D<
+            axis::Axis<f64, search::Binary, bound::Clamp, bound::Clamp, Next>,
+            f64,
+        > = LookupTable1D::new(x.clone(), search::Binary, y.clone()).unwrap();
+        let nearest: LookupTable1D<
+            axis::Axis<f64, search::Binary, bound::Clamp, bound::Clamp, Nearest>,
+            f64,
+        > = LookupTable1D::new(x, search::Binary, y).unwrap();
+
+        for (x, expected) in [
+            (-1., 0.),
+            (0.5, 0.),
+            (1., 10.),
+            (2.9, 20.),
+            (3., 30.),
+            (4., 30.),
+        ] {
+            assert!(previous.lookup(x) == expected);
+        }
+        for (x, expected) in [
+            (-1., 0.),
+            (0., 0.),
+            (0.5, 10.),
+            (1., 10.),
+            (2.1, 30.),
+            (4., 30.),
+        ] {
+            assert!(next.lookup(x) == expected);
+        }
+        for (x, expected) in [
+            (-1., 0.),
+            (0.5, 0.),
+            (0.6, 10.),
+            (2.4, 20.),
+            (2.7, 30.),
+            (4., 30.),
+        ] {
+            assert!(nearest.lookup(x) == expected);
+        }
+
+        let xs = [0.5, 1.5, 2.5];
+        let mut out = [0.; 3];
+        previous.lookup_sorted_batch(&xs, &mut out);
+        assert!(out == [0., 10., 20.]);
+
+        let mut cursor = search::Cursor::new();
+        assert!(next.lookup_with_cursor(&mut cursor, 1.5) == 20.);
+        assert!(nearest.try_lookup(1.4) == Ok(10.));
+
+        // NaN inputs are still propagated
+        assert!(previous.lookup(f64::NAN).is_nan());
+    }
+
+    //
+    // LeastSquares Tests
+    //
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn least_squares_sides() {
+        type FitAxis = axis::Axis<f64, search::Binary, bound::LeastSquares<f64>, bound::Clamp>;
+
+        let x = vec![0., 1., 2., 3.];
+        // y = x, with a noisy first interval
+        let y = vec![0.5, 1., 2., 3.];
+        let table: LookupTable1D<FitAxis, f64> = LookupTable1D::with_bounds(
+            x,
+            search::Binary,
+            bound::LeastSquares::line(4),
+            bound::Clamp,
+            y,
+        )
+        .unwrap();
+
+        // least squares slope of 0.85, through the mean (1.5, 1.625)
+        float_eq::assert_float_eq!(table.lookup(-1.), -0.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.try_lookup(-1.).unwrap(), -0.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(0.5), 0.75, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(4.), 3., abs <= TOL);
+
+        let xs = [-2., -1., 0.5, 4.];
+        let mut out = [0.; 4];
+        table.lookup_sorted_batch(&xs, &mut out);
+        for (x, output) in xs.iter().zip(out.iter()) {
+            float_eq::assert_float_eq!(*output, table.lookup(*x), abs <= TOL);
+        }
+
+        let mut cursor = search::Cursor::new();
+        float_eq::assert_float_eq!(table.lookup_with_cursor(&mut cursor, -1.), -0.5, abs <= TOL);
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn least_squares_too_many_points() {
+        type FitAxis = axis::Axis<f64, search::Binary, bound::Interp, bound::LeastSquares<f64>>;
+
+        let table = LookupTable1D::<FitAxis, f64>::with_bounds(
+            vec![0., 1., 2.],
+            search::Binary,
+            bound::Interp,
+            bound::LeastSquares::quadratic(4),
+            vec![0., 1., 2.],
+        );
+        assert!(matches!(table, Err(Error::FitPoints)));
+    }
+
     //
     // vector valued dependent variables
     //
@@ -1,8 +1,10 @@
 use crate::axis;
 use crate::bound;
 use crate::common;
+use crate::interpolation;
+use crate::nan;
 use crate::search;
-use crate::Error;
+use crate::{AxisValue, Error, LookupError};
 use std::ops::{Add, Div, Mul, Sub};
 
 use ndarray::Array2;
@@ -17,22 +19,47 @@ where
 {
     indep1: Vec<<Axis1 as axis::AxisImpl>::Indep>,
     search1: <Axis1 as axis::AxisImpl>::Search,
+    lower_bound1: <Axis1 as axis::AxisImpl>::LowerBound,
+    upper_bound1: <Axis1 as axis::AxisImpl>::UpperBound,
     indep2: Vec<<Axis2 as axis::AxisImpl>::Indep>,
     search2: <Axis2 as axis::AxisImpl>::Search,
+    lower_bound2: <Axis2 as axis::AxisImpl>::LowerBound,
+    upper_bound2: <Axis2 as axis::AxisImpl>::UpperBound,
     dep: Array2<Dep>,
+    nan_policy: nan::NanPolicy<Dep>,
 }
 
-impl<Indep1, Search1, LowerBound1, UpperBound1, Indep2, Search2, LowerBound2, UpperBound2, Dep>
+impl<
+        Indep1,
+        Search1,
+        LowerBound1,
+        UpperBound1,
+        Interpolation1,
+        Indep2,
+        Search2,
+        LowerBound2,
+        UpperBound2,
+        Interpolation2,
+        Dep,
+    >
     LookupTable2D<
-        axis::Axis<Indep1, Search1, LowerBound1, UpperBound1>,
-        axis::Axis<Indep2, Search2, LowerBound2, UpperBound2>,
+        axis::Axis<Indep1, Search1, LowerBound1, UpperBound1, Interpolation1>,
+        axis::Axis<Indep2, Search2, LowerBound2, UpperBound2, Interpolation2>,
         Dep,
     >
 where
     Indep1: std::cmp::PartialOrd,
+    Search1: search::Search<Indep1>,
+    LowerBound1: bound::Bound<Indep1, Dep>,
+    UpperBound1: bound::Bound<Indep1, Dep>,
     Indep2: std::cmp::PartialOrd,
+    Search2: search::Search<Indep2>,
+    LowerBound2: bound::Bound<Indep2, Dep>,
+    UpperBound2: bound::Bound<Indep2, Dep>,
+    Dep: Clone,
 {
-    /// Construct a new lookup table
+    /// Construct a new lookup table with the default bounds of each axis. See
+    /// [with_bounds](Self::with_bounds) to provide the bounds.
     ///
     /// # Args
     ///
@@ -88,10 +115,64 @@ where
     /// let table = LookupTable2D::<LinearInterpAxis, BinaryClampLowerAxis, f64>::new(x, Linear::new(), y, Binary::new(), f_matrix).unwrap();
     /// ```
     pub fn new(
-        mut indep1: Vec<Indep1>,
+        indep1: Vec<Indep1>,
         search1: Search1,
-        mut indep2: Vec<Indep2>,
+        indep2: Vec<Indep2>,
         search2: Search2,
+        dep: Array2<Dep>,
+    ) -> Result<Self, Error>
+    where
+        LowerBound1: Default,
+        UpperBound1: Default,
+        LowerBound2: Default,
+        UpperBound2: Default,
+    {
+        Self::with_bounds(
+            indep1,
+            search1,
+            LowerBound1::default(),
+            UpperBound1::default(),
+            indep2,
+            search2,
+            LowerBound2::default(),
+            UpperBound2::default(),
+            dep,
+        )
+    }
+
+    /// Construct a new lookup table with the provided bounds
+    ///
+    /// # Args
+    ///
+    /// ## `indep1`, `search1`
+    ///
+    /// List of independent variables (`x` in `f(x, y)`) and its search method.
+    ///
+    /// ## `lower_bound1`, `upper_bound1`
+    ///
+    /// Behavior below and above the bounds of `indep1`. Implements the [Bound](crate::Bound) trait.
+    ///
+    /// ## `indep2`, `search2`
+    ///
+    /// List of independent variables (`y` in `f(x, y)`) and its search method.
+    ///
+    /// ## `lower_bound2`, `upper_bound2`
+    ///
+    /// Behavior below and above the bounds of `indep2`. Implements the [Bound](crate::Bound) trait.
+    ///
+    /// ## `dep`
+    ///
+    /// Dependent variables (`f(x, y)`).
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_bounds(
+        mut indep1: Vec<Indep1>,
+        mut search1: Search1,
+        mut lower_bound1: LowerBound1,
+        mut upper_bound1: UpperBound1,
+        mut indep2: Vec<Indep2>,
+        mut search2: Search2,
+        mut lower_bound2: LowerBound2,
+        mut upper_bound2: UpperBound2,
         mut dep: Array2<Dep>,
     ) -> Result<Self, Error> {
         match common::check_independent_variable(indep1.as_slice())? {
@@ -113,20 +194,76 @@ where
         common::check_lengths(indep1.len(), dep.len_of(ndarray::Axis(0)))?;
         common::check_lengths(indep2.len(), dep.len_of(ndarray::Axis(1)))?;
 
+        if let Some(seam) = bound::periodic_seam(&lower_bound1, &upper_bound1, indep1.as_slice())? {
+            indep1.push(seam);
+            let first = dep.index_axis(ndarray::Axis(0), 0).to_owned();
+            // unwrap is safe here as the first slice has the shape of every other slice
+            dep.push(ndarray::Axis(0), first.view()).unwrap();
+        }
+
+        if let Some(seam) = bound::periodic_seam(&lower_bound2, &upper_bound2, indep2.as_slice())? {
+            indep2.push(seam);
+            let first = dep.index_axis(ndarray::Axis(1), 0).to_owned();
+            // unwrap is safe here as the first slice has the shape of every other slice
+            dep.push(ndarray::Axis(1), first.view()).unwrap();
+        }
+
+        search1.prepare(indep1.as_slice())?;
+        search2.prepare(indep2.as_slice())?;
+
+        lower_bound1.prepare(indep1.as_slice())?;
+        upper_bound1.prepare(indep1.as_slice())?;
+        lower_bound2.prepare(indep2.as_slice())?;
+        upper_bound2.prepare(indep2.as_slice())?;
+
         Ok(Self {
             indep1,
             search1,
+            lower_bound1,
+            upper_bound1,
             indep2,
             search2,
+            lower_bound2,
+            upper_bound2,
             dep,
+            nan_policy: nan::NanPolicy::default(),
         })
     }
+
+    /// Set the treatment of NaN inputs to lookups. Defaults to
+    /// [NanPolicy::Propagate](crate::NanPolicy::Propagate).
+    pub fn with_nan_policy(mut self, nan_policy: nan::NanPolicy<Dep>) -> Self {
+        self.nan_policy = nan_policy;
+        self
+    }
+
+    /// Search method of `indep1`
+    pub fn search1(&self) -> &Search1 {
+        &self.search1
+    }
+
+    /// Search method of `indep2`
+    pub fn search2(&self) -> &Search2 {
+        &self.search2
+    }
 }
 
-impl<Indep1, Search1, LowerBound1, UpperBound1, Indep2, Search2, LowerBound2, UpperBound2, Dep>
+impl<
+        Indep1,
+        Search1,
+        LowerBound1,
+        UpperBound1,
+        Interpolation1,
+        Indep2,
+        Search2,
+        LowerBound2,
+        UpperBound2,
+        Interpolation2,
+        Dep,
+    >
     LookupTable2D<
-        axis::Axis<Indep1, Search1, LowerBound1, UpperBound1>,
-        axis::Axis<Indep2, Search2, LowerBound2, UpperBound2>,
+        axis::Axis<Indep1, Search1, LowerBound1, UpperBound1, Interpolation1>,
+        axis::Axis<Indep2, Search2, LowerBound2, UpperBound2, Interpolation2>,
         Dep,
     >
 where
@@ -142,25 +279,238 @@ where
         + std::fmt::Debug,
     Indep1: Copy
         + Sub<Indep1, Output = Indep1>
+        + Add<Indep1, Output = Indep1>
+        + Mul<Indep1, Output = Indep1>
         + std::cmp::PartialOrd
         + Div<Indep1, Output = Indep1>
         //
         + std::fmt::Debug,
     Indep2: Copy
         + Sub<Indep2, Output = Indep2>
+        + Add<Indep2, Output = Indep2>
+        + Mul<Indep2, Output = Indep2>
         + std::cmp::PartialOrd
         + Div<Indep2, Output = Indep2>
         //
         + std::fmt::Debug,
-    LowerBound1: bound::Bound<Indep1>,
-    UpperBound1: bound::Bound<Indep1>,
-    LowerBound2: bound::Bound<Indep2>,
-    UpperBound2: bound::Bound<Indep2>,
+    LowerBound1: bound::Bound<Indep1, Dep>,
+    UpperBound1: bound::Bound<Indep1, Dep>,
+    LowerBound2: bound::Bound<Indep2, Dep>,
+    UpperBound2: bound::Bound<Indep2, Dep>,
+    Interpolation1: interpolation::Interpolation<Indep1>,
+    Interpolation2: interpolation::Interpolation<Indep2>,
 {
     pub fn lookup(&self, x: Indep1, y: Indep2) -> Dep {
-        let (idx_x_1, idx_x_2) = self.search1.search(x, self.indep1.as_slice());
-        let (idx_y_1, idx_y_2) = self.search2.search(y, self.indep2.as_slice());
+        if nan::is_nan(&x) || nan::is_nan(&y) {
+            return self
+                .nan_policy
+                .value(|| self.interpolate(x, (0, 1), y, (0, 1)));
+        }
+
+        let (bounded_x, negate_x) = self.bound1(x);
+        let x = match bound::stencil(bounded_x, false, |x| {
+            self.search1.search(x, self.indep1.as_slice())
+        }) {
+            Ok(x) => x,
+            Err(early) => return early.unchecked(),
+        };
+        let (bounded_y, negate_y) = self.bound2(y);
+        let y = match bound::stencil(bounded_y, false, |y| {
+            self.search2.search(y, self.indep2.as_slice())
+        }) {
+            Ok(y) => y,
+            Err(early) => return early.unchecked(),
+        };
+
+        bound::negate(&[negate_x, negate_y], self.evaluate(x, y))
+    }
+
+    /// Lookup, returning an error for values beyond a [Reject](crate::Reject) bound, or for NaN
+    /// inputs when the table's [NanPolicy](crate::NanPolicy) is
+    /// [NanPolicy::Error](crate::NanPolicy::Error).
+    ///
+    /// Every axis is checked before returning, so a value rejected on one axis is an error even
+    /// if another axis is bounded by a dependent variable (e.g. [Fill](crate::Fill)).
+    pub fn try_lookup(
+        &self,
+        x: Indep1,
+        y: Indep2,
+    ) -> Result<Dep, LookupError<AxisValue<Indep1, Indep2>>> {
+        let nan_axis = [nan::is_nan(&x), nan::is_nan(&y)]
+            .iter()
+            .position(|is_nan| *is_nan);
+
+        if let Some(axis) = nan_axis {
+            return self
+                .nan_policy
+                .checked(axis, || self.interpolate(x, (0, 1), y, (0, 1)));
+        }
 
+        let (bounded_x, negate_x) = self.bound1(x);
+        let stencil_x = bound::stencil(bounded_x, true, |x| {
+            self.search1.search(x, self.indep1.as_slice())
+        });
+        let (bounded_y, negate_y) = self.bound2(y);
+        let stencil_y = bound::stencil(bounded_y, true, |y| {
+            self.search2.search(y, self.indep2.as_slice())
+        });
+
+        let (x, y) = match (stencil_x, stencil_y) {
+            (Ok(x), Ok(y)) => (x, y),
+            (Err(bound::Early::Reject), _) => {
+                return Err(bound::out_of_bounds(
+                    0,
+                    x,
+                    self.indep1.as_slice(),
+                    AxisValue::X,
+                ))
+            }
+            (_, Err(bound::Early::Reject)) => {
+                return Err(bound::out_of_bounds(
+                    1,
+                    y,
+                    self.indep2.as_slice(),
+                    AxisValue::Y,
+                ))
+            }
+            (Err(bound::Early::Dep(dep)), _) | (_, Err(bound::Early::Dep(dep))) => return Ok(dep),
+        };
+
+        Ok(bound::negate(&[negate_x, negate_y], self.evaluate(x, y)))
+    }
+
+    /// Lookup using the caller owned `cursor` to search the independent variables, instead of the
+    /// table's search methods. See [Cursor](crate::Cursor) for more information.
+    pub fn lookup_with_cursor(&self, cursor: &mut search::Cursor, x: Indep1, y: Indep2) -> Dep {
+        if nan::is_nan(&x) || nan::is_nan(&y) {
+            return self
+                .nan_policy
+                .value(|| self.interpolate(x, (0, 1), y, (0, 1)));
+        }
+
+        let (bounded_x, negate_x) = self.bound1(x);
+        let x = match bound::stencil(bounded_x, false, |x| {
+            cursor.search(0, x, self.indep1.as_slice())
+        }) {
+            Ok(x) => x,
+            Err(early) => return early.unchecked(),
+        };
+        let (bounded_y, negate_y) = self.bound2(y);
+        let y = match bound::stencil(bounded_y, false, |y| {
+            cursor.search(1, y, self.indep2.as_slice())
+        }) {
+            Ok(y) => y,
+            Err(early) => return early.unchecked(),
+        };
+
+        bound::negate(&[negate_x, negate_y], self.evaluate(x, y))
+    }
+
+    /// Lookup every value in `xs` at the same `y`, writing the results to the same index of `out`.
+    ///
+    /// `xs` should be sorted in increasing order, in which case `indep1` is searched by walking it
+    /// alongside `xs` in a single pass. Values smaller than the one before them are found with the
+    /// table's search method instead.
+    ///
+    /// # Panics
+    ///
+    /// If `xs` and `out` have different lengths
+    pub fn lookup_sorted_batch(&self, xs: &[Indep1], y: Indep2, out: &mut [Dep]) {
+        assert_eq!(
+            xs.len(),
+            out.len(),
+            "input and output of a batch lookup must have the same length"
+        );
+
+        if nan::is_nan(&y) {
+            for (x, out) in xs.iter().zip(out.iter_mut()) {
+                *out = self
+                    .nan_policy
+                    .value(|| self.interpolate(*x, (0, 1), y, (0, 1)));
+            }
+            return;
+        }
+
+        let (bounded_y, negate_y) = self.bound2(y);
+        let y_stencil = match bound::stencil(bounded_y, false, |y| {
+            self.search2.search(y, self.indep2.as_slice())
+        }) {
+            Ok(y) => y,
+            Err(early) => {
+                out.fill(early.unchecked());
+                return;
+            }
+        };
+
+        let mut merge = search::SortedMerge::default();
+
+        for (x, out) in xs.iter().zip(out.iter_mut()) {
+            if nan::is_nan(x) {
+                *out = self
+                    .nan_policy
+                    .value(|| self.interpolate(*x, (0, 1), y, (0, 1)));
+                continue;
+            }
+
+            let (bounded_x, negate_x) = self.bound1(*x);
+            let x = match bound::stencil(bounded_x, false, |x| {
+                merge.search(&self.search1, x, self.indep1.as_slice())
+            }) {
+                Ok(x) => x,
+                Err(early) => {
+                    *out = early.unchecked();
+                    continue;
+                }
+            };
+
+            *out = bound::negate(&[negate_x, negate_y], self.evaluate(x, y_stencil));
+        }
+    }
+
+    fn bound1(&self, x: Indep1) -> (bound::Bounded<Indep1, Dep>, bound::Negation<Dep>) {
+        bound::apply(
+            &self.lower_bound1,
+            &self.upper_bound1,
+            x,
+            self.indep1.as_slice(),
+        )
+    }
+
+    fn bound2(&self, y: Indep2) -> (bound::Bounded<Indep2, Dep>, bound::Negation<Dep>) {
+        bound::apply(
+            &self.lower_bound2,
+            &self.upper_bound2,
+            y,
+            self.indep2.as_slice(),
+        )
+    }
+
+    fn evaluate(&self, x: bound::Stencil<Indep1>, y: bound::Stencil<Indep2>) -> Dep {
+        let x = x.position::<Interpolation1>(self.indep1.as_slice());
+        let y = y.position::<Interpolation2>(self.indep2.as_slice());
+
+        match (x, y) {
+            (bound::Stencil::Interpolate(x, x_pair), bound::Stencil::Interpolate(y, y_pair)) => {
+                self.interpolate(x, x_pair, y, y_pair)
+            }
+            (x, y) => bound::sum(x.weights(self.indep1.as_slice()).flat_map(
+                |(idx_x, x_weight)| {
+                    y.weights(self.indep2.as_slice())
+                        .map(move |(idx_y, y_weight)| {
+                            self.dep[[idx_x, idx_y]] * x_weight * y_weight
+                        })
+                },
+            )),
+        }
+    }
+
+    fn interpolate(
+        &self,
+        x: Indep1,
+        (idx_x_1, idx_x_2): (usize, usize),
+        y: Indep2,
+        (idx_y_1, idx_y_2): (usize, usize),
+    ) -> Dep {
         let x_1: Indep1 = self.indep1[idx_x_1];
         let x_2: Indep1 = self.indep1[idx_x_2];
 
@@ -172,16 +522,6 @@ where
         let f_2_1: Dep = self.dep[[idx_x_2, idx_y_1]];
         let f_2_2: Dep = self.dep[[idx_x_2, idx_y_2]];
 
-        // bound x acording to the axis we are interpolating on
-        // unwrap is safe here as we have checked its at least length 2
-        let x = LowerBound1::lower_bound(x, *self.indep1.first().unwrap());
-        let x = UpperBound1::upper_bound(x, *self.indep1.last().unwrap());
-
-        // bound y acording to the axis we are interpolating on
-        // unwrap is safe here as we have checked its at least length 2
-        let y = LowerBound2::lower_bound(y, *self.indep2.first().unwrap());
-        let y = UpperBound2::upper_bound(y, *self.indep2.last().unwrap());
-
         let x_slope1 = (x_2 - x) / (x_2 - x_1);
         let x_slope2 = (x - x_1) / (x_2 - x_1);
         let y_slope1 = (y_2 - y) / (y_2 - y_1);
@@ -195,6 +535,7 @@ where
 }
 
 #[cfg(test)]
+#[allow(clippy::default_constructed_unit_structs)]
 mod tests {
     use super::*;
 
@@ -362,4 +703,511 @@ mod tests {
         let output = table.lookup(x, y);
         float_eq::assert_float_eq!(output, func(x, y), abs <= TOL);
     }
+
+    //
+    // LogSpaced Tests
+    //
+
+    #[test]
+    fn log_spaced_1() {
+        type LogSpacedAxis = axis::Axis<f64, search::LogSpaced, bound::Interp, bound::Interp>;
+
+        let x: Vec<f64> = (0..20).map(|idx| 1.5_f64.powi(idx)).collect();
+        let y = x.clone();
+        let mut f = ndarray::Array2::zeros((x.len(), y.len()));
+
+        for i in 0..x.len() {
+            for j in 0..y.len() {
+                f[[i, j]] = func(x[i], y[j]);
+            }
+        }
+
+        let table: LookupTable2D<LogSpacedAxis, LinearAxis, f64> =
+            LookupTable2D::new(x, search::LogSpaced::new(), y, search::Linear, f).unwrap();
+
+        let x = 7.3;
+        let y = 100.2;
+        let output = table.lookup(x, y);
+        float_eq::assert_float_eq!(output, func(x, y), abs <= TOL);
+    }
+
+    //
+    // AutoSearch Tests
+    //
+
+    #[test]
+    fn auto_search_selection() {
+        type AutoAxis = axis::Axis<f64, search::AutoSearch, bound::Interp, bound::Interp>;
+
+        let (x, _, _) = data();
+        let y = vec![0., 1., 3.];
+        let mut f = ndarray::Array2::zeros((x.len(), y.len()));
+
+        for i in 0..x.len() {
+            for j in 0..y.len() {
+                f[[i, j]] = func(x[i], y[j]);
+            }
+        }
+
+        let table: LookupTable2D<AutoAxis, AutoAxis, f64> = LookupTable2D::new(
+            x,
+            search::AutoSearch::new(),
+            y,
+            search::AutoSearch::new(),
+            f,
+        )
+        .unwrap();
+
+        assert!(table.search1().strategy() == search::SearchStrategy::FixedDelta);
+        assert!(table.search2().strategy() == search::SearchStrategy::Linear);
+
+        let output = table.lookup(1.7, 2.5);
+        float_eq::assert_float_eq!(output, func(1.7, 2.5), abs <= TOL);
+    }
+
+    //
+    // NaN Policy Tests
+    //
+
+    #[test]
+    fn nan_propagate() {
+        let table = linear_simple_table();
+        assert!(table.lookup(f64::NAN, 1.0).is_nan());
+        assert!(table.lookup(1.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn nan_error() {
+        let table = linear_simple_table().with_nan_policy(nan::NanPolicy::Error);
+        assert!(matches!(
+            table.try_lookup(1.0, f64::NAN),
+            Err(LookupError::NanInput { axis: 1 })
+        ));
+        assert!(matches!(
+            table.try_lookup(f64::NAN, f64::NAN),
+            Err(LookupError::NanInput { axis: 0 })
+        ));
+        float_eq::assert_float_eq!(
+            table.try_lookup(1.0, 2.0).unwrap(),
+            func(1.0, 2.0),
+            abs <= TOL
+        );
+    }
+
+    #[test]
+    fn nan_substitute() {
+        let table = linear_simple_table().with_nan_policy(nan::NanPolicy::Substitute(-1.));
+        assert!(table.lookup(f64::NAN, 1.0) == -1.);
+
+        let mut cursor = search::Cursor::new();
+        assert!(table.lookup_with_cursor(&mut cursor, 1.0, f64::NAN) == -1.);
+
+        let mut out = [0.; 3];
+        table.lookup_sorted_batch(&[0.5, f64::NAN, 1.5], 1.0, &mut out);
+        float_eq::assert_float_eq!(out, [func(0.5, 1.0), -1., func(1.5, 1.0)], abs_all <= TOL);
+
+        table.lookup_sorted_batch(&[0.5, 1.0, 1.5], f64::NAN, &mut out);
+        assert!(out == [-1.; 3]);
+    }
+
+    //
+    // Bound Instance Tests
+    //
+
+    #[test]
+    fn with_bounds_matches_new() {
+        type ClampAxis = axis::Axis<f64, search::Linear, bound::Clamp, bound::Clamp>;
+        let (x, y, f) = data();
+
+        let table: LookupTable2D<ClampAxis, LinearAxis, f64> = LookupTable2D::with_bounds(
+            x.clone(),
+            search::Linear,
+            bound::Clamp,
+            bound::Clamp,
+            y.clone(),
+            search::Linear,
+            bound::Interp,
+            bound::Interp,
+            f.clone(),
+        )
+        .unwrap();
+        let default_table: LookupTable2D<ClampAxis, LinearAxis, f64> =
+            LookupTable2D::new(x, search::Linear, y, search::Linear, f).unwrap();
+
+        for (x, y) in [(-1.0, 2.0), (1.0, 7.0), (6.0, -2.0)] {
+            float_eq::assert_float_eq!(table.lookup(x, y), default_table.lookup(x, y), abs <= TOL);
+        }
+    }
+
+    //
+    // Fill Tests
+    //
+
+    #[test]
+    fn fill_per_axis() {
+        type FillAxis = axis::Axis<f64, search::Binary, bound::Fill<f64>, bound::Fill<f64>>;
+        let (x, y, f) = data();
+        let table: LookupTable2D<LinearAxis, FillAxis, f64> = LookupTable2D::with_bounds(
+            x,
+            search::Linear,
+            bound::Interp,
+            bound::Interp,
+            y,
+            search::Binary,
+            bound::Fill::new(-1.),
+            bound::Fill::new(-2.),
+            f,
+        )
+        .unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(1.0, 2.0), func(1.0, 2.0), abs <= TOL);
+        // first axis extrapolates
+        float_eq::assert_float_eq!(table.lookup(6.0, 2.0), func(6.0, 2.0), abs <= TOL);
+        assert!(table.lookup(1.0, -1.0) == -1.);
+        assert!(table.lookup(6.0, 6.0) == -2.);
+        assert!(table.try_lookup(1.0, 6.0) == Ok(-2.));
+
+        let mut out = [0.; 2];
+        table.lookup_sorted_batch(&[1.0, 2.0], 6.0, &mut out);
+        assert!(out == [-2.; 2]);
+    }
+
+    //
+    // InterpMargin Tests
+    //
+
+    #[test]
+    fn interp_margin_first_axis() {
+        type MarginAxis =
+            axis::Axis<f64, search::Binary, bound::InterpMargin<f64>, bound::InterpMargin<f64>>;
+        let (x, y, f) = data();
+        let table: LookupTable2D<MarginAxis, LinearAxis, f64> = LookupTable2D::with_bounds(
+            x,
+            search::Binary,
+            bound::InterpMargin::absolute(0.5),
+            bound::InterpMargin::fraction_of_span(0.2),
+            y,
+            search::Linear,
+            bound::Interp,
+            bound::Interp,
+            f,
+        )
+        .unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(-0.25, 1.0), func(-0.25, 1.0), abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(-3.0, 1.0), func(-0.5, 1.0), abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(5.5, 1.0), func(5.5, 1.0), abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(10.0, 1.0), func(6.0, 1.0), abs <= TOL);
+    }
+
+    //
+    // Periodic Tests
+    //
+
+    #[test]
+    fn periodic_second_axis() {
+        type PeriodicAxis =
+            axis::Axis<f64, search::Binary, bound::Periodic<f64>, bound::Periodic<f64>>;
+
+        let x = vec![0., 1., 2.];
+        let y = vec![0., 90., 180., 270.];
+        let f = ndarray::array![[0., 1., 2., 3.], [10., 11., 12., 13.], [20., 21., 22., 23.]];
+
+        let table: LookupTable2D<LinearAxis, PeriodicAxis, f64> = LookupTable2D::with_bounds(
+            x,
+            search::Linear,
+            bound::Interp,
+            bound::Interp,
+            y,
+            search::Binary,
+            bound::Periodic::new(360.),
+            bound::Periodic::new(360.),
+            f,
+        )
+        .unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(0.5, 45.), 5.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(0.5, 315.), 6.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(1.0, -45.), 11.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(2.0, 720.), 20., abs <= TOL);
+    }
+
+    //
+    // Interpolation Tests
+    //
+
+    #[test]
+    fn interpolation_per_axis() {
+        type GearAxis =
+            axis::Axis<f64, search::Linear, bound::Clamp, bound::Clamp, interpolation::Nearest>;
+        type SpeedAxis = axis::Axis<f64, search::Binary, bound::Clamp, bound::Clamp>;
+
+        let gear = vec![1., 2., 3.];
+        let speed = vec![0., 50., 100.];
+        let f = ndarray::array![[0., 150., 300.], [0., 100., 200.], [0., 50., 100.]];
+
+        let table: LookupTable2D<GearAxis, SpeedAxis, f64> =
+            LookupTable2D::new(gear, search::Linear, speed, search::Binary, f).unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(1., 25.), 75., abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(1.4, 25.), 75., abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(1.6, 75.), 150., abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(3.5, 75.), 75., abs <= TOL);
+        float_eq::assert_float_eq!(table.try_lookup(2.2, 60.).unwrap(), 120., abs <= TOL);
+
+        let xs = [1., 1.4, 1.6, 3.5];
+        let mut out = [0.; 4];
+        table.lookup_sorted_batch(&xs, 75., &mut out);
+        for (x, output) in xs.iter().zip(out.iter()) {
+            float_eq::assert_float_eq!(*output, table.lookup(*x, 75.), abs <= TOL);
+        }
+    }
+
+    //
+    // Reflect Tests
+    //
+
+    #[test]
+    fn reflect_antisymmetric_axes() {
+        type ReflectAxis = axis::Axis<f64, search::Binary, bound::Reflect<f64>, bound::Interp>;
+
+        // f(x, y) = x * y, stored for positive x and y only
+        let x = vec![0., 1., 2.];
+        let y = vec![0., 10., 20.];
+        let f = ndarray::array![[0., 0., 0.], [0., 10., 20.], [0., 20., 40.]];
+
+        let table: LookupTable2D<ReflectAxis, ReflectAxis, f64> = LookupTable2D::with_bounds(
+            x,
+            search::Binary,
+            bound::Reflect::new().antisymmetric(),
+            bound::Interp,
+            y,
+            search::Binary,
+            bound::Reflect::new().antisymmetric(),
+            bound::Interp,
+            f,
+        )
+        .unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(1.5, 5.), 7.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(1.5, -5.), -7.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(-1.5, 5.), -7.5, abs <= TOL);
+        // negating both axes cancels out
+        float_eq::assert_float_eq!(table.lookup(-1.5, -5.), 7.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.try_lookup(-1.5, -5.).unwrap(), 7.5, abs <= TOL);
+
+        let xs = [-2.0, -0.5, 0.5, 2.0];
+        let mut out = [0.; 4];
+        table.lookup_sorted_batch(&xs, -5., &mut out);
+        for (x, output) in xs.iter().zip(out.iter()) {
+            float_eq::assert_float_eq!(*output, table.lookup(*x, -5.), abs <= TOL);
+        }
+    }
+
+    //
+    // LeastSquares Tests
+    //
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn least_squares_axes() {
+        type QuadraticAxis =
+            axis::Axis<f64, search::Binary, bound::LeastSquares<f64>, bound::LeastSquares<f64>>;
+        type LineAxis = axis::Axis<f64, search::Linear, bound::Clamp, bound::LeastSquares<f64>>;
+
+        // f(x, y) = x^2 + 3y
+        let x = vec![0., 1., 2., 3.];
+        let y = vec![0., 1., 2.];
+        let f = ndarray::Array2::from_shape_fn((4, 3), |(i, j)| x[i] * x[i] + 3. * y[j]);
+
+        let table: LookupTable2D<QuadraticAxis, LineAxis, f64> = LookupTable2D::with_bounds(
+            x,
+            search::Binary,
+            bound::LeastSquares::quadratic(3),
+            bound::LeastSquares::quadratic(4),
+            y,
+            search::Linear,
+            bound::Clamp,
+            bound::LeastSquares::line(3),
+            f,
+        )
+        .unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(6., 1.5), 40.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(-1., 1.5), 5.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(6., 4.), 48., abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(1., 4.), 13., abs <= TOL);
+        float_eq::assert_float_eq!(table.try_lookup(-1., 4.).unwrap(), 13., abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(6., -1.), 36., abs <= TOL);
+
+        let xs = [-1., 1., 6.];
+        let mut out = [0.; 3];
+        table.lookup_sorted_batch(&xs, 4., &mut out);
+        for (x, output) in xs.iter().zip(out.iter()) {
+            float_eq::assert_float_eq!(*output, table.lookup(*x, 4.), abs <= TOL);
+        }
+    }
+
+    //
+    // Reject Tests
+    //
+
+    #[test]
+    fn reject_try_lookup() {
+        type RejectAxis = axis::Axis<f64, search::Binary, bound::Reject, bound::Reject>;
+        let (x, y, f) = data();
+        let table: LookupTable2D<LinearAxis, RejectAxis, f64> =
+            LookupTable2D::new(x, search::Linear, y, search::Binary, f).unwrap();
+
+        float_eq::assert_float_eq!(
+            table.try_lookup(1.0, 2.0).unwrap(),
+            func(1.0, 2.0),
+            abs <= TOL
+        );
+        // first axis extrapolates freely
+        float_eq::assert_float_eq!(
+            table.try_lookup(7.0, 2.0).unwrap(),
+            func(7.0, 2.0),
+            abs <= TOL
+        );
+
+        assert!(
+            table.try_lookup(1.0, 5.5)
+                == Err(LookupError::OutOfBounds {
+                    axis: 1,
+                    value: AxisValue::Y(5.5),
+                    lower: AxisValue::Y(0.),
+                    upper: AxisValue::Y(5.)
+                })
+        );
+    }
+
+    #[test]
+    /// a value rejected on one axis is an error, even if another axis fills
+    fn reject_try_lookup_fill_other_axis() {
+        type FillAxis = axis::Axis<f64, search::Linear, bound::Fill<f64>, bound::Fill<f64>>;
+        type RejectAxis = axis::Axis<f64, search::Binary, bound::Reject, bound::Reject>;
+        let (x, y, f) = data();
+        let table: LookupTable2D<FillAxis, RejectAxis, f64> = LookupTable2D::with_bounds(
+            x,
+            search::Linear,
+            bound::Fill::new(-1.),
+            bound::Fill::new(-2.),
+            y,
+            search::Binary,
+            bound::Reject,
+            bound::Reject,
+            f,
+        )
+        .unwrap();
+
+        assert!(table.try_lookup(6.0, 2.0) == Ok(-2.));
+        assert!(matches!(
+            table.try_lookup(6.0, 5.5),
+            Err(LookupError::OutOfBounds { axis: 1, .. })
+        ));
+    }
+
+    /// dependent variable that can be scaled by either axis type
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Scalar(f64);
+
+    impl Add for Scalar {
+        type Output = Scalar;
+        fn add(self, rhs: Scalar) -> Scalar {
+            Scalar(self.0 + rhs.0)
+        }
+    }
+
+    impl Sub for Scalar {
+        type Output = Scalar;
+        fn sub(self, rhs: Scalar) -> Scalar {
+            Scalar(self.0 - rhs.0)
+        }
+    }
+
+    impl Mul<f32> for Scalar {
+        type Output = Scalar;
+        fn mul(self, rhs: f32) -> Scalar {
+            Scalar(self.0 * rhs as f64)
+        }
+    }
+
+    impl Mul<f64> for Scalar {
+        type Output = Scalar;
+        fn mul(self, rhs: f64) -> Scalar {
+            Scalar(self.0 * rhs)
+        }
+    }
+
+    impl Div<f32> for Scalar {
+        type Output = Scalar;
+        fn div(self, rhs: f32) -> Scalar {
+            Scalar(self.0 / rhs as f64)
+        }
+    }
+
+    #[test]
+    fn reject_try_lookup_mixed_types() {
+        type GearAxis = axis::Axis<f32, search::Linear, bound::Reject, bound::Reject>;
+        type RejectAxis = axis::Axis<f64, search::Binary, bound::Reject, bound::Reject>;
+        let x = vec![1f32, 2., 3.];
+        let y = vec![0., 10.];
+        let f = ndarray::Array2::from_shape_fn((3, 2), |(i, j)| Scalar(x[i] as f64 + y[j]));
+        let table: LookupTable2D<GearAxis, RejectAxis, Scalar> =
+            LookupTable2D::new(x, search::Linear, y, search::Binary, f).unwrap();
+
+        assert!(table.try_lookup(2.0, 5.0) == Ok(Scalar(7.0)));
+        assert!(
+            table.try_lookup(4.0, 5.0)
+                == Err(LookupError::OutOfBounds {
+                    axis: 0,
+                    value: AxisValue::X(4.),
+                    lower: AxisValue::X(1.),
+                    upper: AxisValue::X(3.)
+                })
+        );
+        assert!(
+            table.try_lookup(2.0, -1.0)
+                == Err(LookupError::OutOfBounds {
+                    axis: 1,
+                    value: AxisValue::Y(-1.),
+                    lower: AxisValue::Y(0.),
+                    upper: AxisValue::Y(10.)
+                })
+        );
+    }
+
+    //
+    // Sorted Batch Tests
+    //
+
+    #[test]
+    fn sorted_batch_matches_lookup() {
+        let table = linear_simple_table();
+        let xs = [-1.0, 0.0, 0.5, 2.2, 2.2, 4.9, 5.0, 8.0];
+        let y = 3.3;
+        let mut out = [0.; 8];
+
+        table.lookup_sorted_batch(&xs, y, &mut out);
+
+        for (x, output) in xs.iter().zip(out.iter()) {
+            float_eq::assert_float_eq!(*output, table.lookup(*x, y), abs <= TOL);
+        }
+    }
+
+    //
+    // Cursor Tests
+    //
+
+    #[test]
+    fn cursor_matches_lookup() {
+        let table = linear_simple_table();
+        let mut cursor = search::Cursor::new();
+
+        for (x, y) in [(0.5, 2.5), (4.9, 0.1), (-1.0, 6.0), (2.2, 2.2), (0.0, 5.0)] {
+            let output = table.lookup_with_cursor(&mut cursor, x, y);
+            float_eq::assert_float_eq!(output, table.lookup(x, y), abs <= TOL);
+        }
+    }
 }
@@ -1,8 +1,10 @@
 use crate::axis;
 use crate::bound;
 use crate::common;
+use crate::interpolation;
+use crate::nan;
 use crate::search;
-use crate::Error;
+use crate::{AxisValue, Error, LookupError};
 use std::ops::{Add, Div, Mul, Sub};
 
 use ndarray::Array3;
@@ -19,11 +21,18 @@ where
 {
     indep1: Vec<<Axis1 as axis::AxisImpl>::Indep>,
     search1: <Axis1 as axis::AxisImpl>::Search,
+    lower_bound1: <Axis1 as axis::AxisImpl>::LowerBound,
+    upper_bound1: <Axis1 as axis::AxisImpl>::UpperBound,
     indep2: Vec<<Axis2 as axis::AxisImpl>::Indep>,
     search2: <Axis2 as axis::AxisImpl>::Search,
+    lower_bound2: <Axis2 as axis::AxisImpl>::LowerBound,
+    upper_bound2: <Axis2 as axis::AxisImpl>::UpperBound,
     indep3: Vec<<Axis3 as axis::AxisImpl>::Indep>,
     search3: <Axis3 as axis::AxisImpl>::Search,
+    lower_bound3: <Axis3 as axis::AxisImpl>::LowerBound,
+    upper_bound3: <Axis3 as axis::AxisImpl>::UpperBound,
     dep: Array3<Dep>,
+    nan_policy: nan::NanPolicy<Dep>,
 }
 
 impl<
@@ -31,28 +40,42 @@ impl<
         Search1,
         LowerBound1,
         UpperBound1,
+        Interpolation1,
         Indep2,
         Search2,
         LowerBound2,
         UpperBound2,
+        Interpolation2,
         Indep3,
         Search3,
         LowerBound3,
         UpperBound3,
+        Interpolation3,
         Dep,
     >
     LookupTable3D<
-        axis::Axis<Indep1, Search1, LowerBound1, UpperBound1>,
-        axis::Axis<Indep2, Search2, LowerBound2, UpperBound2>,
-        axis::Axis<Indep3, Search3, LowerBound3, UpperBound3>,
+        axis::Axis<Indep1, Search1, LowerBound1, UpperBound1, Interpolation1>,
+        axis::Axis<Indep2, Search2, LowerBound2, UpperBound2, Interpolation2>,
+        axis::Axis<Indep3, Search3, LowerBound3, UpperBound3, Interpolation3>,
         Dep,
     >
 where
     Indep1: std::cmp::PartialOrd,
+    Search1: search::Search<Indep1>,
+    LowerBound1: bound::Bound<Indep1, Dep>,
+    UpperBound1: bound::Bound<Indep1, Dep>,
     Indep2: std::cmp::PartialOrd,
+    Search2: search::Search<Indep2>,
+    LowerBound2: bound::Bound<Indep2, Dep>,
+    UpperBound2: bound::Bound<Indep2, Dep>,
     Indep3: std::cmp::PartialOrd,
+    Search3: search::Search<Indep3>,
+    LowerBound3: bound::Bound<Indep3, Dep>,
+    UpperBound3: bound::Bound<Indep3, Dep>,
+    Dep: Clone,
 {
-    /// Construct a new lookup table
+    /// Construct a new lookup table with the default bounds of each axis. See
+    /// [with_bounds](Self::with_bounds) to provide the bounds.
     ///
     /// # Args
     ///
@@ -126,12 +149,84 @@ where
     /// let table = Table3D::new(x, Linear::new(), y, Binary::new(), z, Linear::new(), f_matrix).unwrap();
     /// ```
     pub fn new(
-        mut indep1: Vec<Indep1>,
+        indep1: Vec<Indep1>,
         search1: Search1,
-        mut indep2: Vec<Indep2>,
+        indep2: Vec<Indep2>,
         search2: Search2,
-        mut indep3: Vec<Indep3>,
+        indep3: Vec<Indep3>,
         search3: Search3,
+        dep: Array3<Dep>,
+    ) -> Result<Self, Error>
+    where
+        LowerBound1: Default,
+        UpperBound1: Default,
+        LowerBound2: Default,
+        UpperBound2: Default,
+        LowerBound3: Default,
+        UpperBound3: Default,
+    {
+        Self::with_bounds(
+            indep1,
+            search1,
+            LowerBound1::default(),
+            UpperBound1::default(),
+            indep2,
+            search2,
+            LowerBound2::default(),
+            UpperBound2::default(),
+            indep3,
+            search3,
+            LowerBound3::default(),
+            UpperBound3::default(),
+            dep,
+        )
+    }
+
+    /// Construct a new lookup table with the provided bounds
+    ///
+    /// # Args
+    ///
+    /// ## `indep1`, `search1`
+    ///
+    /// List of independent variables (`x` in `f(x, y, z)`) and its search method.
+    ///
+    /// ## `lower_bound1`, `upper_bound1`
+    ///
+    /// Behavior below and above the bounds of `indep1`. Implements the [Bound](crate::Bound) trait.
+    ///
+    /// ## `indep2`, `search2`
+    ///
+    /// List of independent variables (`y` in `f(x, y, z)`) and its search method.
+    ///
+    /// ## `lower_bound2`, `upper_bound2`
+    ///
+    /// Behavior below and above the bounds of `indep2`. Implements the [Bound](crate::Bound) trait.
+    ///
+    /// ## `indep3`, `search3`
+    ///
+    /// List of independent variables (`z` in `f(x, y, z)`) and its search method.
+    ///
+    /// ## `lower_bound3`, `upper_bound3`
+    ///
+    /// Behavior below and above the bounds of `indep3`. Implements the [Bound](crate::Bound) trait.
+    ///
+    /// ## `dep`
+    ///
+    /// Dependent variables (`f(x, y, z)`).
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_bounds(
+        mut indep1: Vec<Indep1>,
+        mut search1: Search1,
+        mut lower_bound1: LowerBound1,
+        mut upper_bound1: UpperBound1,
+        mut indep2: Vec<Indep2>,
+        mut search2: Search2,
+        mut lower_bound2: LowerBound2,
+        mut upper_bound2: UpperBound2,
+        mut indep3: Vec<Indep3>,
+        mut search3: Search3,
+        mut lower_bound3: LowerBound3,
+        mut upper_bound3: UpperBound3,
         mut dep: Array3<Dep>,
     ) -> Result<Self, Error> {
         match common::check_independent_variable(indep1.as_slice())? {
@@ -162,16 +257,77 @@ where
         common::check_lengths(indep2.len(), dep.len_of(ndarray::Axis(1)))?;
         common::check_lengths(indep3.len(), dep.len_of(ndarray::Axis(2)))?;
 
+        if let Some(seam) = bound::periodic_seam(&lower_bound1, &upper_bound1, indep1.as_slice())? {
+            indep1.push(seam);
+            let first = dep.index_axis(ndarray::Axis(0), 0).to_owned();
+            // unwrap is safe here as the first slice has the shape of every other slice
+            dep.push(ndarray::Axis(0), first.view()).unwrap();
+        }
+
+        if let Some(seam) = bound::periodic_seam(&lower_bound2, &upper_bound2, indep2.as_slice())? {
+            indep2.push(seam);
+            let first = dep.index_axis(ndarray::Axis(1), 0).to_owned();
+            // unwrap is safe here as the first slice has the shape of every other slice
+            dep.push(ndarray::Axis(1), first.view()).unwrap();
+        }
+
+        if let Some(seam) = bound::periodic_seam(&lower_bound3, &upper_bound3, indep3.as_slice())? {
+            indep3.push(seam);
+            let first = dep.index_axis(ndarray::Axis(2), 0).to_owned();
+            // unwrap is safe here as the first slice has the shape of every other slice
+            dep.push(ndarray::Axis(2), first.view()).unwrap();
+        }
+
+        search1.prepare(indep1.as_slice())?;
+        search2.prepare(indep2.as_slice())?;
+        search3.prepare(indep3.as_slice())?;
+
+        lower_bound1.prepare(indep1.as_slice())?;
+        upper_bound1.prepare(indep1.as_slice())?;
+        lower_bound2.prepare(indep2.as_slice())?;
+        upper_bound2.prepare(indep2.as_slice())?;
+        lower_bound3.prepare(indep3.as_slice())?;
+        upper_bound3.prepare(indep3.as_slice())?;
+
         Ok(Self {
             indep1,
             search1,
+            lower_bound1,
+            upper_bound1,
             indep2,
             search2,
+            lower_bound2,
+            upper_bound2,
             indep3,
             search3,
+            lower_bound3,
+            upper_bound3,
             dep,
+            nan_policy: nan::NanPolicy::default(),
         })
     }
+
+    /// Set the treatment of NaN inputs to lookups. Defaults to
+    /// [NanPolicy::Propagate](crate::NanPolicy::Propagate).
+    pub fn with_nan_policy(mut self, nan_policy: nan::NanPolicy<Dep>) -> Self {
+        self.nan_policy = nan_policy;
+        self
+    }
+
+    /// Search method of `indep1`
+    pub fn search1(&self) -> &Search1 {
+        &self.search1
+    }
+
+    /// Search method of `indep2`
+    pub fn search2(&self) -> &Search2 {
+        &self.search2
+    }
+
+    /// Search method of `indep3`
+    pub fn search3(&self) -> &Search3 {
+        &self.search3
+    }
 }
 
 impl<
@@ -179,20 +335,23 @@ impl<
         Search1,
         LowerBound1,
         UpperBound1,
+        Interpolation1,
         Indep2,
         Search2,
         LowerBound2,
         UpperBound2,
+        Interpolation2,
         Indep3,
         Search3,
         LowerBound3,
         UpperBound3,
+        Interpolation3,
         Dep,
     >
     LookupTable3D<
-        axis::Axis<Indep1, Search1, LowerBound1, UpperBound1>,
-        axis::Axis<Indep2, Search2, LowerBound2, UpperBound2>,
-        axis::Axis<Indep3, Search3, LowerBound3, UpperBound3>,
+        axis::Axis<Indep1, Search1, LowerBound1, UpperBound1, Interpolation1>,
+        axis::Axis<Indep2, Search2, LowerBound2, UpperBound2, Interpolation2>,
+        axis::Axis<Indep3, Search3, LowerBound3, UpperBound3, Interpolation3>,
         Dep,
     >
 where
@@ -205,10 +364,13 @@ where
         + Div<Indep1, Output = Dep>
         + Mul<Indep1, Output = Dep>
         + Mul<Indep2, Output = Dep>
+        + Mul<Indep3, Output = Dep>
         + Add<Dep, Output = Dep>
         + std::fmt::Debug,
     Indep1: Copy
         + Sub<Indep1, Output = Indep1>
+        + Add<Indep1, Output = Indep1>
+        + Mul<Indep1, Output = Indep1>
         + std::cmp::PartialOrd
         + Div<Indep1, Output = Indep1>
         + One
@@ -216,38 +378,325 @@ where
         + std::fmt::Debug,
     Indep2: Copy
         + Sub<Indep2, Output = Indep2>
+        + Add<Indep2, Output = Indep2>
+        + Mul<Indep2, Output = Indep2>
         + std::cmp::PartialOrd
         + Div<Indep2, Output = Indep2>
         + One
         //
         + std::fmt::Debug,
     Indep3: Copy
-        + Sub<Indep2, Output = Indep2>
+        + Sub<Indep3, Output = Indep3>
+        + Add<Indep3, Output = Indep3>
+        + Mul<Indep3, Output = Indep3>
         + std::cmp::PartialOrd
-        + Div<Indep2, Output = Indep2>
+        + Div<Indep3, Output = Indep3>
         + One
         //
         + std::fmt::Debug,
-    LowerBound1: bound::Bound<Indep1>,
-    UpperBound1: bound::Bound<Indep1>,
-    LowerBound2: bound::Bound<Indep2>,
-    UpperBound2: bound::Bound<Indep2>,
-    LowerBound3: bound::Bound<Indep3>,
-    UpperBound3: bound::Bound<Indep3>,
+    LowerBound1: bound::Bound<Indep1, Dep>,
+    UpperBound1: bound::Bound<Indep1, Dep>,
+    LowerBound2: bound::Bound<Indep2, Dep>,
+    UpperBound2: bound::Bound<Indep2, Dep>,
+    LowerBound3: bound::Bound<Indep3, Dep>,
+    UpperBound3: bound::Bound<Indep3, Dep>,
+    Interpolation1: interpolation::Interpolation<Indep1>,
+    Interpolation2: interpolation::Interpolation<Indep2>,
+    Interpolation3: interpolation::Interpolation<Indep3>,
 {
     pub fn lookup(&self, x: Indep1, y: Indep2, z: Indep3) -> Dep {
-        let (idx_x_1, idx_x_2) = self.search1.search(x, self.indep1.as_slice());
-        let (idx_y_1, idx_y_2) = self.search2.search(y, self.indep2.as_slice());
-        let (idx_z_1, idx_z_2) = self.search3.search(z, self.indep3.as_slice());
+        if nan::is_nan(&x) || nan::is_nan(&y) || nan::is_nan(&z) {
+            return self
+                .nan_policy
+                .value(|| self.interpolate(x, (0, 1), y, (0, 1), z, (0, 1)));
+        }
+
+        let (bounded_x, negate_x) = self.bound1(x);
+        let x = match bound::stencil(bounded_x, false, |x| {
+            self.search1.search(x, self.indep1.as_slice())
+        }) {
+            Ok(x) => x,
+            Err(early) => return early.unchecked(),
+        };
+        let (bounded_y, negate_y) = self.bound2(y);
+        let y = match bound::stencil(bounded_y, false, |y| {
+            self.search2.search(y, self.indep2.as_slice())
+        }) {
+            Ok(y) => y,
+            Err(early) => return early.unchecked(),
+        };
+        let (bounded_z, negate_z) = self.bound3(z);
+        let z = match bound::stencil(bounded_z, false, |z| {
+            self.search3.search(z, self.indep3.as_slice())
+        }) {
+            Ok(z) => z,
+            Err(early) => return early.unchecked(),
+        };
+
+        bound::negate(&[negate_x, negate_y, negate_z], self.evaluate(x, y, z))
+    }
+
+    /// Lookup, returning an error for values beyond a [Reject](crate::Reject) bound, or for NaN
+    /// inputs when the table's [NanPolicy](crate::NanPolicy) is
+    /// [NanPolicy::Error](crate::NanPolicy::Error).
+    ///
+    /// Every axis is checked before returning, so a value rejected on one axis is an error even
+    /// if another axis is bounded by a dependent variable (e.g. [Fill](crate::Fill)).
+    pub fn try_lookup(
+        &self,
+        x: Indep1,
+        y: Indep2,
+        z: Indep3,
+    ) -> Result<Dep, LookupError<AxisValue<Indep1, Indep2, Indep3>>> {
+        let nan_axis = [nan::is_nan(&x), nan::is_nan(&y), nan::is_nan(&z)]
+            .iter()
+            .position(|is_nan| *is_nan);
+
+        if let Some(axis) = nan_axis {
+            return self
+                .nan_policy
+                .checked(axis, || self.interpolate(x, (0, 1), y, (0, 1), z, (0, 1)));
+        }
+
+        let (bounded_x, negate_x) = self.bound1(x);
+        let stencil_x = bound::stencil(bounded_x, true, |x| {
+            self.search1.search(x, self.indep1.as_slice())
+        });
+        let (bounded_y, negate_y) = self.bound2(y);
+        let stencil_y = bound::stencil(bounded_y, true, |y| {
+            self.search2.search(y, self.indep2.as_slice())
+        });
+        let (bounded_z, negate_z) = self.bound3(z);
+        let stencil_z = bound::stencil(bounded_z, true, |z| {
+            self.search3.search(z, self.indep3.as_slice())
+        });
+
+        let (x, y, z) = match (stencil_x, stencil_y, stencil_z) {
+            (Ok(x), Ok(y), Ok(z)) => (x, y, z),
+            (Err(bound::Early::Reject), _, _) => {
+                return Err(bound::out_of_bounds(
+                    0,
+                    x,
+                    self.indep1.as_slice(),
+                    AxisValue::X,
+                ))
+            }
+            (_, Err(bound::Early::Reject), _) => {
+                return Err(bound::out_of_bounds(
+                    1,
+                    y,
+                    self.indep2.as_slice(),
+                    AxisValue::Y,
+                ))
+            }
+            (_, _, Err(bound::Early::Reject)) => {
+                return Err(bound::out_of_bounds(
+                    2,
+                    z,
+                    self.indep3.as_slice(),
+                    AxisValue::Z,
+                ))
+            }
+            (Err(bound::Early::Dep(dep)), _, _)
+            | (_, Err(bound::Early::Dep(dep)), _)
+            | (_, _, Err(bound::Early::Dep(dep))) => return Ok(dep),
+        };
+
+        Ok(bound::negate(
+            &[negate_x, negate_y, negate_z],
+            self.evaluate(x, y, z),
+        ))
+    }
+
+    /// Lookup using the caller owned `cursor` to search the independent variables, instead of the
+    /// table's search methods. See [Cursor](crate::Cursor) for more information.
+    pub fn lookup_with_cursor(
+        &self,
+        cursor: &mut search::Cursor,
+        x: Indep1,
+        y: Indep2,
+        z: Indep3,
+    ) -> Dep {
+        if nan::is_nan(&x) || nan::is_nan(&y) || nan::is_nan(&z) {
+            return self
+                .nan_policy
+                .value(|| self.interpolate(x, (0, 1), y, (0, 1), z, (0, 1)));
+        }
+
+        let (bounded_x, negate_x) = self.bound1(x);
+        let x = match bound::stencil(bounded_x, false, |x| {
+            cursor.search(0, x, self.indep1.as_slice())
+        }) {
+            Ok(x) => x,
+            Err(early) => return early.unchecked(),
+        };
+        let (bounded_y, negate_y) = self.bound2(y);
+        let y = match bound::stencil(bounded_y, false, |y| {
+            cursor.search(1, y, self.indep2.as_slice())
+        }) {
+            Ok(y) => y,
+            Err(early) => return early.unchecked(),
+        };
+        let (bounded_z, negate_z) = self.bound3(z);
+        let z = match bound::stencil(bounded_z, false, |z| {
+            cursor.search(2, z, self.indep3.as_slice())
+        }) {
+            Ok(z) => z,
+            Err(early) => return early.unchecked(),
+        };
+
+        bound::negate(&[negate_x, negate_y, negate_z], self.evaluate(x, y, z))
+    }
+
+    /// Lookup every value in `xs` at the same `y` and `z`, writing the results to the same index of
+    /// `out`.
+    ///
+    /// `xs` should be sorted in increasing order, in which case `indep1` is searched by walking it
+    /// alongside `xs` in a single pass. Values smaller than the one before them are found with the
+    /// table's search method instead.
+    ///
+    /// # Panics
+    ///
+    /// If `xs` and `out` have different lengths
+    pub fn lookup_sorted_batch(&self, xs: &[Indep1], y: Indep2, z: Indep3, out: &mut [Dep]) {
+        assert_eq!(
+            xs.len(),
+            out.len(),
+            "input and output of a batch lookup must have the same length"
+        );
+
+        if nan::is_nan(&y) || nan::is_nan(&z) {
+            for (x, out) in xs.iter().zip(out.iter_mut()) {
+                *out = self
+                    .nan_policy
+                    .value(|| self.interpolate(*x, (0, 1), y, (0, 1), z, (0, 1)));
+            }
+            return;
+        }
+
+        let (bounded_y, negate_y) = self.bound2(y);
+        let y_stencil = match bound::stencil(bounded_y, false, |y| {
+            self.search2.search(y, self.indep2.as_slice())
+        }) {
+            Ok(y) => y,
+            Err(early) => {
+                out.fill(early.unchecked());
+                return;
+            }
+        };
+        let (bounded_z, negate_z) = self.bound3(z);
+        let z_stencil = match bound::stencil(bounded_z, false, |z| {
+            self.search3.search(z, self.indep3.as_slice())
+        }) {
+            Ok(z) => z,
+            Err(early) => {
+                out.fill(early.unchecked());
+                return;
+            }
+        };
+
+        let mut merge = search::SortedMerge::default();
+
+        for (x, out) in xs.iter().zip(out.iter_mut()) {
+            if nan::is_nan(x) {
+                *out = self
+                    .nan_policy
+                    .value(|| self.interpolate(*x, (0, 1), y, (0, 1), z, (0, 1)));
+                continue;
+            }
+
+            let (bounded_x, negate_x) = self.bound1(*x);
+            let x = match bound::stencil(bounded_x, false, |x| {
+                merge.search(&self.search1, x, self.indep1.as_slice())
+            }) {
+                Ok(x) => x,
+                Err(early) => {
+                    *out = early.unchecked();
+                    continue;
+                }
+            };
+
+            *out = bound::negate(
+                &[negate_x, negate_y, negate_z],
+                self.evaluate(x, y_stencil, z_stencil),
+            );
+        }
+    }
 
+    fn bound1(&self, x: Indep1) -> (bound::Bounded<Indep1, Dep>, bound::Negation<Dep>) {
+        bound::apply(
+            &self.lower_bound1,
+            &self.upper_bound1,
+            x,
+            self.indep1.as_slice(),
+        )
+    }
+
+    fn bound2(&self, y: Indep2) -> (bound::Bounded<Indep2, Dep>, bound::Negation<Dep>) {
+        bound::apply(
+            &self.lower_bound2,
+            &self.upper_bound2,
+            y,
+            self.indep2.as_slice(),
+        )
+    }
+
+    fn bound3(&self, z: Indep3) -> (bound::Bounded<Indep3, Dep>, bound::Negation<Dep>) {
+        bound::apply(
+            &self.lower_bound3,
+            &self.upper_bound3,
+            z,
+            self.indep3.as_slice(),
+        )
+    }
+
+    fn evaluate(
+        &self,
+        x: bound::Stencil<Indep1>,
+        y: bound::Stencil<Indep2>,
+        z: bound::Stencil<Indep3>,
+    ) -> Dep {
+        let x = x.position::<Interpolation1>(self.indep1.as_slice());
+        let y = y.position::<Interpolation2>(self.indep2.as_slice());
+        let z = z.position::<Interpolation3>(self.indep3.as_slice());
+
+        match (x, y, z) {
+            (
+                bound::Stencil::Interpolate(x, x_pair),
+                bound::Stencil::Interpolate(y, y_pair),
+                bound::Stencil::Interpolate(z, z_pair),
+            ) => self.interpolate(x, x_pair, y, y_pair, z, z_pair),
+            (x, y, z) => bound::sum(x.weights(self.indep1.as_slice()).flat_map(
+                |(idx_x, x_weight)| {
+                    y.weights(self.indep2.as_slice())
+                        .flat_map(move |(idx_y, y_weight)| {
+                            z.weights(self.indep3.as_slice())
+                                .map(move |(idx_z, z_weight)| {
+                                    self.dep[[idx_x, idx_y, idx_z]] * x_weight * y_weight * z_weight
+                                })
+                        })
+                },
+            )),
+        }
+    }
+
+    fn interpolate(
+        &self,
+        x: Indep1,
+        (idx_x_1, idx_x_2): (usize, usize),
+        y: Indep2,
+        (idx_y_1, idx_y_2): (usize, usize),
+        z: Indep3,
+        (idx_z_1, idx_z_2): (usize, usize),
+    ) -> Dep {
         let x_1: Indep1 = self.indep1[idx_x_1];
         let x_2: Indep1 = self.indep1[idx_x_2];
 
         let y_1: Indep2 = self.indep2[idx_y_1];
         let y_2: Indep2 = self.indep2[idx_y_2];
 
-        let z_1: Indep2 = self.indep2[idx_z_1];
-        let z_2: Indep2 = self.indep2[idx_z_2];
+        let z_1: Indep3 = self.indep3[idx_z_1];
+        let z_2: Indep3 = self.indep3[idx_z_2];
 
         // function evaluations at the bounding indices
         let f_1_1_1: Dep = self.dep[[idx_x_1, idx_y_1, idx_z_1]];
@@ -259,21 +708,6 @@ where
         let f_1_2_2: Dep = self.dep[[idx_x_1, idx_y_2, idx_z_2]];
         let f_2_2_2: Dep = self.dep[[idx_x_2, idx_y_2, idx_z_2]];
 
-        // bound x acording to the axis we are interpolating on
-        // unwrap is safe here as we have checked its at least length 2
-        let x = LowerBound1::lower_bound(x, *self.indep1.first().unwrap());
-        let x = UpperBound1::upper_bound(x, *self.indep1.last().unwrap());
-
-        // bound y acording to the axis we are interpolating on
-        // unwrap is safe here as we have checked its at least length 2
-        let y = LowerBound2::lower_bound(y, *self.indep2.first().unwrap());
-        let y = UpperBound2::upper_bound(y, *self.indep2.last().unwrap());
-
-        // bound z acording to the axis we are interpolating on
-        // unwrap is safe here as we have checked its at least length 2
-        let z = LowerBound3::lower_bound(z, *self.indep3.first().unwrap());
-        let z = UpperBound3::upper_bound(z, *self.indep3.last().unwrap());
-
         let x_d = (x - x_1) / (x_2 - x_1);
         let y_d = (y - y_1) / (y_2 - y_1);
         let z_d = (z - z_1) / (z_2 - z_1);
@@ -291,6 +725,7 @@ where
 }
 
 #[cfg(test)]
+#[allow(clippy::default_constructed_unit_structs)]
 mod tests {
     use super::*;
 
@@ -519,4 +954,347 @@ mod tests {
         let output = table.lookup(x, y, z);
         float_eq::assert_float_eq!(output, func(x, y, z), abs <= TOL);
     }
+
+    #[test]
+    /// the weights along the third axis must come from the third independent variable, which
+    /// differs from the second here in both length and values
+    fn linear_distinct_axes() {
+        let x = vec![0., 1., 2.];
+        let y = vec![-3., -1., 0., 4.];
+        let z = vec![10., 20., 50.];
+        let f = Array3::from_shape_fn((x.len(), y.len(), z.len()), |(i, j, k)| {
+            func(x[i], y[j], z[k])
+        });
+
+        let table: TableLinLinLin =
+            LookupTable3D::new(x, search::Linear, y, search::Linear, z, search::Linear, f).unwrap();
+
+        for (x, y, z) in [(0.5, -2., 15.), (1.5, 2., 35.), (2., 0.5, 12.5)] {
+            let output = table.lookup(x, y, z);
+            float_eq::assert_float_eq!(output, func(x, y, z), abs <= TOL);
+        }
+    }
+
+    //
+    // LogSpaced Tests
+    //
+
+    #[test]
+    fn log_spaced_1() {
+        type LogSpacedAxis = axis::Axis<f64, search::LogSpaced, bound::Interp, bound::Interp>;
+
+        let x: Vec<f64> = (0..10).map(|idx| 2_f64.powi(idx)).collect();
+        let y = x.clone();
+        let z = x.clone();
+        let mut f = ndarray::Array3::zeros((x.len(), y.len(), z.len()));
+
+        for i in 0..x.len() {
+            for j in 0..y.len() {
+                for k in 0..z.len() {
+                    f[[i, j, k]] = func(x[i], y[j], z[k]);
+                }
+            }
+        }
+
+        let table: LookupTable3D<LogSpacedAxis, LinearAxis, LogSpacedAxis, f64> =
+            LookupTable3D::new(
+                x,
+                search::LogSpaced::new(),
+                y,
+                search::Linear,
+                z,
+                search::LogSpaced::new(),
+                f,
+            )
+            .unwrap();
+
+        let x = 7.3;
+        let y = 100.2;
+        let z = 3.1;
+        let output = table.lookup(x, y, z);
+        float_eq::assert_float_eq!(output, func(x, y, z), abs <= TOL);
+    }
+
+    //
+    // NaN Policy Tests
+    //
+
+    #[test]
+    fn nan_propagate() {
+        let table = linear_simple_table();
+        assert!(table.lookup(1.0, 1.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn nan_error() {
+        let table = linear_simple_table().with_nan_policy(nan::NanPolicy::Error);
+        assert!(matches!(
+            table.try_lookup(1.0, 1.0, f64::NAN),
+            Err(LookupError::NanInput { axis: 2 })
+        ));
+        float_eq::assert_float_eq!(
+            table.try_lookup(1.0, 2.0, 3.0).unwrap(),
+            table.lookup(1.0, 2.0, 3.0),
+            abs <= TOL
+        );
+    }
+
+    #[test]
+    fn nan_substitute() {
+        let table = linear_simple_table().with_nan_policy(nan::NanPolicy::Substitute(-1.));
+        assert!(table.lookup(1.0, f64::NAN, 1.0) == -1.);
+
+        let mut cursor = search::Cursor::new();
+        assert!(table.lookup_with_cursor(&mut cursor, f64::NAN, 1.0, 1.0) == -1.);
+
+        let mut out = [0.; 2];
+        table.lookup_sorted_batch(&[0.5, 1.5], 1.0, f64::NAN, &mut out);
+        assert!(out == [-1.; 2]);
+    }
+
+    //
+    // Fill Tests
+    //
+
+    #[test]
+    fn fill_per_axis() {
+        type ClampAxis = axis::Axis<f64, search::Binary, bound::Clamp, bound::Clamp>;
+        type FillAxis = axis::Axis<f64, search::Binary, bound::Clamp, bound::Fill<f64>>;
+        let (x, y, z, f) = data();
+        let table: LookupTable3D<FillAxis, ClampAxis, ClampAxis, f64> = LookupTable3D::with_bounds(
+            x,
+            search::Binary,
+            bound::Clamp,
+            bound::Fill::new(f64::NAN),
+            y,
+            search::Binary,
+            bound::Clamp,
+            bound::Clamp,
+            z,
+            search::Binary,
+            bound::Clamp,
+            bound::Clamp,
+            f,
+        )
+        .unwrap();
+
+        assert!(!table.lookup(1.0, 2.0, 3.0).is_nan());
+        assert!(!table.lookup(-1.0, 2.0, 3.0).is_nan());
+        assert!(table.lookup(6.0, 2.0, 3.0).is_nan());
+
+        let mut out = [0.; 3];
+        table.lookup_sorted_batch(&[1.0, 5.0, 6.0], 2.0, 3.0, &mut out);
+        assert!(!out[0].is_nan());
+        assert!(!out[1].is_nan());
+        assert!(out[2].is_nan());
+    }
+
+    //
+    // Periodic Tests
+    //
+
+    #[test]
+    fn periodic_first_axis() {
+        type PeriodicAxis =
+            axis::Axis<f64, search::Binary, bound::Periodic<f64>, bound::Periodic<f64>>;
+        type ClampAxis = axis::Axis<f64, search::Linear, bound::Clamp, bound::Clamp>;
+
+        let x = vec![0., 1., 2., 3.];
+        let y = vec![0., 1.];
+        let z = vec![0., 1.];
+        // f(x, y, z) = x + y + z on the table, periodic in x with period 4
+        let f = Array3::from_shape_fn((4, 2, 2), |(i, j, k)| x[i] + y[j] + z[k]);
+
+        let table: LookupTable3D<PeriodicAxis, ClampAxis, ClampAxis, f64> =
+            LookupTable3D::with_bounds(
+                x,
+                search::Binary,
+                bound::Periodic::new(4.),
+                bound::Periodic::new(4.),
+                y,
+                search::Linear,
+                bound::Clamp,
+                bound::Clamp,
+                z,
+                search::Linear,
+                bound::Clamp,
+                bound::Clamp,
+                f,
+            )
+            .unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(1.5, 0.5, 0.5), 2.5, abs <= TOL);
+        // across the seam, x = 3 gives 3 and x = 4 wraps to x = 0, giving 0
+        float_eq::assert_float_eq!(table.lookup(3.5, 0., 0.), 1.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(-0.5, 1., 1.), 3.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(5.5, 1., 0.), 2.5, abs <= TOL);
+    }
+
+    //
+    // Interpolation Tests
+    //
+
+    #[test]
+    fn interpolation_per_axis() {
+        type PreviousAxis =
+            axis::Axis<f64, search::Binary, bound::Clamp, bound::Clamp, interpolation::Previous>;
+        type NextAxis =
+            axis::Axis<f64, search::Binary, bound::Clamp, bound::Clamp, interpolation::Next>;
+
+        let x = vec![0., 1., 2.];
+        let y = vec![0., 1., 2.];
+        let z = vec![0., 1., 2.];
+        let f =
+            ndarray::Array3::from_shape_fn((3, 3, 3), |(i, j, k)| 100. * x[i] + 10. * y[j] + z[k]);
+
+        let table: LookupTable3D<PreviousAxis, NextAxis, LinearAxis, f64> =
+            LookupTable3D::new(x, search::Binary, y, search::Binary, z, search::Linear, f).unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(1.5, 0.5, 0.5), 110.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(2., 1., 1.5), 211.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.try_lookup(0.9, 1.1, 1.5).unwrap(), 21.5, abs <= TOL);
+    }
+
+    //
+    // LeastSquares Tests
+    //
+
+    #[test]
+    fn least_squares_mixed_axes() {
+        type QuadraticAxis =
+            axis::Axis<f64, search::Binary, bound::Clamp, bound::LeastSquares<f64>>;
+
+        // f(x, y, z) = x^2 + y + z
+        let x = vec![0., 1., 2., 3.];
+        let y = vec![0., 1., 2.];
+        let z = vec![0., 1., 2.];
+        let f = ndarray::Array3::from_shape_fn((4, 3, 3), |(i, j, k)| x[i] * x[i] + y[j] + z[k]);
+
+        let table: LookupTable3D<QuadraticAxis, LinearAxis, LinearAxis, f64> =
+            LookupTable3D::with_bounds(
+                x,
+                search::Binary,
+                bound::Clamp,
+                bound::LeastSquares::quadratic(3),
+                y,
+                search::Linear,
+                bound::Interp,
+                bound::Interp,
+                z,
+                search::Linear,
+                bound::Interp,
+                bound::Interp,
+                f,
+            )
+            .unwrap();
+
+        float_eq::assert_float_eq!(table.lookup(6., 1.5, -1.), 36.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.lookup(4., 3., 0.5), 19.5, abs <= TOL);
+        float_eq::assert_float_eq!(table.try_lookup(4., 3., 0.5).unwrap(), 19.5, abs <= TOL);
+
+        let xs = [-1., 1., 4., 6.];
+        let mut out = [0.; 4];
+        table.lookup_sorted_batch(&xs, 1.5, -1., &mut out);
+        for (x, output) in xs.iter().zip(out.iter()) {
+            float_eq::assert_float_eq!(*output, table.lookup(*x, 1.5, -1.), abs <= TOL);
+        }
+    }
+
+    //
+    // Reject Tests
+    //
+
+    #[test]
+    fn reject_try_lookup() {
+        type RejectAxis = axis::Axis<f64, search::Binary, bound::Reject, bound::Reject>;
+        let (x, y, z, f) = data();
+        let table: LookupTable3D<RejectAxis, RejectAxis, RejectAxis, f64> =
+            LookupTable3D::new(x, search::Binary, y, search::Binary, z, search::Binary, f).unwrap();
+
+        assert!(table.try_lookup(1.0, 2.0, 3.0).is_ok());
+        assert!(matches!(
+            table.try_lookup(1.0, 2.0, -3.0),
+            Err(LookupError::OutOfBounds { axis: 2, .. })
+        ));
+        assert!(matches!(
+            table.try_lookup(-1.0, 2.0, -3.0),
+            Err(LookupError::OutOfBounds { axis: 0, .. })
+        ));
+    }
+
+    #[test]
+    /// a value rejected on one axis is an error, even if an earlier axis fills
+    fn reject_try_lookup_fill_other_axis() {
+        type FillAxis = axis::Axis<f64, search::Binary, bound::Fill<f64>, bound::Fill<f64>>;
+        type RejectAxis = axis::Axis<f64, search::Binary, bound::Reject, bound::Reject>;
+        let (x, y, z, f) = data();
+        let table: LookupTable3D<FillAxis, LinearAxis, RejectAxis, f64> =
+            LookupTable3D::with_bounds(
+                x,
+                search::Binary,
+                bound::Fill::new(-1.),
+                bound::Fill::new(-2.),
+                y,
+                search::Linear,
+                bound::Interp,
+                bound::Interp,
+                z,
+                search::Binary,
+                bound::Reject,
+                bound::Reject,
+                f,
+            )
+            .unwrap();
+
+        assert!(table.try_lookup(-1.0, 2.0, 3.0) == Ok(-1.));
+        assert!(
+            table.try_lookup(-1.0, 2.0, -3.0)
+                == Err(LookupError::OutOfBounds {
+                    axis: 2,
+                    value: AxisValue::Z(-3.),
+                    lower: AxisValue::Z(0.),
+                    upper: AxisValue::Z(5.)
+                })
+        );
+    }
+
+    //
+    // Sorted Batch Tests
+    //
+
+    #[test]
+    fn sorted_batch_matches_lookup() {
+        let table = linear_simple_table();
+        let xs = [-1.0, 0.0, 0.5, 2.2, 2.2, 4.9, 5.0, 8.0];
+        let y = 3.3;
+        let z = 1.2;
+        let mut out = [0.; 8];
+
+        table.lookup_sorted_batch(&xs, y, z, &mut out);
+
+        for (x, output) in xs.iter().zip(out.iter()) {
+            float_eq::assert_float_eq!(*output, table.lookup(*x, y, z), abs <= TOL);
+        }
+    }
+
+    //
+    // Cursor Tests
+    //
+
+    #[test]
+    fn cursor_matches_lookup() {
+        let table = linear_simple_table();
+        let mut cursor = search::Cursor::new();
+
+        for (x, y, z) in [
+            (0.5, 2.5, 4.2),
+            (4.9, 0.1, 0.3),
+            (-1.0, 6.0, 2.0),
+            (2.2, 2.2, 2.2),
+            (0.0, 5.0, 5.0),
+        ] {
+            let output = table.lookup_with_cursor(&mut cursor, x, y, z);
+            float_eq::assert_float_eq!(output, table.lookup(x, y, z), abs <= TOL);
+        }
+    }
 }
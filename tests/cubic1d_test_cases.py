@@ -0,0 +1,73 @@
+"""
+Generate tests/pchip_cases.json, lookups of SciPy's `PchipInterpolator` for the integration tests of
+`CubicTable1D::pchip`. Run from the root of the repository:
+
+    python3 tests/cubic1d_test_cases.py
+
+SciPy is required, and the version used is recorded in the output.
+"""
+
+import json
+import sys
+from dataclasses import dataclass
+
+try:
+    import scipy
+    from scipy.interpolate import PchipInterpolator
+except ImportError:
+    sys.exit("SciPy is required to generate tests/pchip_cases.json")
+
+
+@dataclass
+class Table:
+    name: str
+    x: list[float]
+    y: list[float]
+
+
+TABLES = [
+    # non-uniform interval widths, monotone
+    Table("non_uniform", [0.0, 0.5, 2.0, 2.3, 5.0, 9.0], [0.0, 1.0, 1.5, 3.0, 4.0, 8.0]),
+    # secants changing sign at local extrema
+    Table("sign_changes", [0.0, 1.0, 3.0, 4.0, 7.0, 8.0], [0.0, 2.0, -1.0, 3.0, 3.5, -2.0]),
+    # flat runs between steps
+    Table("flat_runs", [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0], [1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 0.0]),
+    # the three point end slope has the opposite sign to the end secant, and is set to zero
+    Table("edge_zero", [0.0, 1.0, 2.0, 3.0], [0.0, 1.0, 6.0, 7.0]),
+    # the secants change sign and the three point end slope is larger than three times the end
+    # secant, and is limited to three times the end secant
+    Table("edge_three_secants", [0.0, 1.0, 2.0, 3.0], [0.0, 1.0, -3.0, -2.0]),
+    # non-uniform widths at the edges
+    Table("edge_non_uniform", [0.0, 1.0, 4.0, 4.5, 6.0], [0.0, 1.0, 0.0, 2.0, -5.0]),
+]
+
+
+def linspace(start: float, stop: float, num: int) -> list[float]:
+    return [start + (stop - start) * i / (num - 1) for i in range(num)]
+
+
+def main():
+    output_json = {
+        "generator": f"scipy {scipy.__version__}",
+        "pchip": [],
+    }
+
+    for table in TABLES:
+        # extrapolates beyond the table, like `Interp` bounds
+        inputs = linspace(table.x[0] - 1.0, table.x[-1] + 1.0, 41)
+        outputs = PchipInterpolator(table.x, table.y, extrapolate=True)(inputs)
+
+        output_json["pchip"].append({
+            "name": table.name,
+            "x": table.x,
+            "y": table.y,
+            "input": inputs,
+            "output": [float(value) for value in outputs],
+        })
+
+    with open("tests/pchip_cases.json", "w") as f:
+        json.dump(output_json, f, indent=1)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,51 @@
+use lookup_tables::*;
+use serde::Deserialize;
+use std::path::PathBuf;
+
+const TOL: f64 = 1e-12;
+
+#[derive(Deserialize)]
+/// Deserialize data generated by `cubic1d_test_cases.py`
+struct InputsPchip {
+    /// SciPy version that generated the outputs
+    generator: String,
+    pchip: Vec<TestCase>,
+}
+
+impl InputsPchip {
+    fn load() -> Self {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/pchip_cases.json");
+        let reader = std::fs::File::open(&path).unwrap();
+        serde_json::from_reader(reader).unwrap()
+    }
+}
+
+#[derive(Deserialize)]
+struct TestCase {
+    name: String,
+    x: Vec<f64>,
+    y: Vec<f64>,
+    input: Vec<f64>,
+    output: Vec<f64>,
+}
+
+#[test]
+#[ignore = "requires tests/pchip_cases.json, generated with SciPy by tests/cubic1d_test_cases.py"]
+fn pchip() {
+    let cases = InputsPchip::load();
+    println!("outputs generated by {}", cases.generator);
+    assert!(cases.generator.starts_with("scipy "));
+
+    for case in cases.pchip {
+        println!("evaluating case {}", case.name);
+
+        let table: CubicTable1D<Axis<f64, Binary, Interp, Interp>, f64> =
+            CubicTable1D::pchip(case.x, Binary, case.y).unwrap();
+
+        for (input, output) in case.input.into_iter().zip(case.output) {
+            println!("evaluating case input {input}");
+
+            float_eq::assert_float_eq!(table.lookup(input), output, abs <= TOL);
+        }
+    }
+}
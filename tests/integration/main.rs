@@ -1 +1,3 @@
+#[cfg(feature = "num-traits")]
+mod cubic1d;
 mod table1d;
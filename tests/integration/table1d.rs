@@ -1,3 +1,5 @@
+#![allow(clippy::default_constructed_unit_structs, clippy::useless_conversion)]
+
 use lookup_tables::*;
 use serde::Deserialize;
 use std::path::PathBuf;
@@ -93,3 +95,62 @@ fn cached_linear_cell() {
         }
     }
 }
+
+type ClampedTable<S> = LookupTable1D<Axis<f64, S, Clamp, Clamp>, f64>;
+
+/// Construct a clamped table with the search method from `search` for every test case, and check
+/// it with `check`
+fn for_each_clamped_case<S>(search: impl Fn() -> S, check: impl Fn(&ClampedTable<S>, &TestCase))
+where
+    S: Search<f64>,
+{
+    let cases = Inputs1D::load();
+
+    for (idx, case) in cases.clamped.into_iter().enumerate() {
+        println!("evaluating case index {idx}");
+
+        dbg!(&case.x);
+        dbg!(&case.y);
+
+        let table = ClampedTable::new(case.x.clone(), search(), case.y.clone()).unwrap();
+        check(&table, &case);
+    }
+}
+
+/// Every input of the test case should produce the expected output
+fn assert_case_outputs<S>(table: &ClampedTable<S>, case: &TestCase)
+where
+    S: Search<f64>,
+{
+    for (input, output) in case.input.iter().zip(case.output.iter()) {
+        let table_output = table.lookup(*input);
+        float_eq::assert_float_eq!(table_output, *output, abs <= TOL, "input {input}");
+    }
+}
+
+#[test]
+fn bucket_index() {
+    for_each_clamped_case(BucketIndex::default, assert_case_outputs);
+}
+
+#[test]
+fn cached_linear_atomic() {
+    // share the table across threads, each looking up every input
+    for_each_clamped_case(CachedLinearAtomic::default, |table, case| {
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| assert_case_outputs(table, case));
+            }
+        });
+    });
+}
+
+#[test]
+fn cached_hunt() {
+    for_each_clamped_case(CachedHunt::default, assert_case_outputs);
+}
+
+#[test]
+fn interpolation_search() {
+    for_each_clamped_case(InterpolationSearch::new, assert_case_outputs);
+}